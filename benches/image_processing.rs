@@ -0,0 +1,139 @@
+// benches/image_processing.rs - Prétraitement OCR : tables (LUT) vs calcul par pixel
+//
+// `cargo bench --bench image_processing`
+
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use elden_ring_compteur::ocr::ocr::BOSS_VARIANTS;
+use elden_ring_compteur::structs::settings::color_profile::ColorProfile;
+use elden_ring_compteur::utils::image_processing::{
+    boss_variant, extract_text_channel, gamma_contrast_lut, gamma_lut, preprocess_v2_fallback,
+    upscale_boss_crop,
+};
+use image::imageops::FilterType;
+use image::{DynamicImage, GrayImage, Rgba, RgbaImage};
+
+/// Résolutions testées (nom, largeur, hauteur)
+const RESOLUTIONS: &[(&str, u32, u32)] = &[
+    ("1080p", 1920, 1080),
+    ("1440p", 2560, 1440),
+    ("4K", 3840, 2160),
+];
+
+/// Image pseudo-aléatoire reproductible, proportionnelle à la résolution
+fn synthetic_crop(width: u32, height: u32) -> DynamicImage {
+    let mut seed = 0x2545_f491_u32;
+    DynamicImage::ImageRgba8(RgbaImage::from_fn(width, height, |_, _| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        let [r, g, b, _] = seed.to_le_bytes();
+        Rgba([r, g, b, 255])
+    }))
+}
+
+// ------------------------------------------------------------------
+// Ancienne implémentation (référence) : powf par pixel, copies, 7 Lanczos3
+// ------------------------------------------------------------------
+fn legacy_adjust_gamma(img: &GrayImage, gamma: f32) -> GrayImage {
+    let mut result = img.clone();
+    for pixel in result.pixels_mut() {
+        pixel[0] = ((pixel[0] as f32 / 255.0).powf(gamma) * 255.0) as u8;
+    }
+    result
+}
+
+fn legacy_increase_contrast(img: &GrayImage, factor: f32) -> GrayImage {
+    let mut result = img.clone();
+    for pixel in result.pixels_mut() {
+        pixel[0] = ((pixel[0] as f32 - 128.0) * factor + 128.0).clamp(0.0, 255.0) as u8;
+    }
+    result
+}
+
+fn legacy_death_v2(channel: &GrayImage) -> DynamicImage {
+    let contrast = legacy_increase_contrast(&legacy_adjust_gamma(channel, 0.3), 2.0);
+    let (w, h) = contrast.dimensions();
+    let scale = 120.0 / h as f32;
+    DynamicImage::ImageLuma8(contrast).resize(
+        (w as f32 * scale) as u32,
+        120,
+        FilterType::CatmullRom,
+    )
+}
+
+fn legacy_boss_variant(
+    dyn_image: &DynamicImage,
+    gamma: f32,
+    contrast: Option<f32>,
+) -> DynamicImage {
+    let mut enhanced = dyn_image.to_luma8().clone();
+    for pixel in enhanced.pixels_mut() {
+        let corrected = (pixel[0] as f32 / 255.0).powf(gamma);
+        pixel[0] = match contrast {
+            Some(c) => (((corrected - 0.5) * c + 0.5).clamp(0.0, 1.0) * 255.0) as u8,
+            None => (corrected * 255.0).clamp(0.0, 255.0) as u8,
+        };
+    }
+    let (w, h) = enhanced.dimensions();
+    DynamicImage::ImageLuma8(enhanced).resize(w * 4, h * 4, FilterType::Lanczos3)
+}
+
+// ------------------------------------------------------------------
+// Benchmarks
+// ------------------------------------------------------------------
+fn bench_death_preprocess(c: &mut Criterion) {
+    let profile = ColorProfile::elden_ring();
+    let mut group = c.benchmark_group("death_preprocess");
+    for (name, width, height) in RESOLUTIONS {
+        // Zone de mort par défaut : 39 % x 10 % de l'interface
+        let crop = synthetic_crop(width * 39 / 100, height / 10);
+        let channel = extract_text_channel(&crop, &profile);
+
+        group.bench_with_input(BenchmarkId::new("legacy", name), &channel, |b, channel| {
+            b.iter(|| legacy_death_v2(black_box(channel)))
+        });
+        group.bench_with_input(BenchmarkId::new("lut", name), &channel, |b, channel| {
+            b.iter(|| preprocess_v2_fallback(black_box(channel)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_boss_variants(c: &mut Criterion) {
+    let mut group = c.benchmark_group("boss_variants");
+    group.sample_size(20);
+    for (name, width, height) in RESOLUTIONS {
+        // Barre de boss par défaut : 53 % x 5 % de l'interface
+        let crop = synthetic_crop(width * 53 / 100, height / 20);
+
+        group.bench_with_input(BenchmarkId::new("legacy", name), &crop, |b, crop| {
+            b.iter(|| {
+                BOSS_VARIANTS
+                    .iter()
+                    .map(|(gamma, contrast)| {
+                        legacy_boss_variant(black_box(crop), *gamma, *contrast)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("lut", name), &crop, |b, crop| {
+            b.iter(|| {
+                let upscaled = upscale_boss_crop(black_box(crop));
+                BOSS_VARIANTS
+                    .iter()
+                    .map(|(gamma, contrast)| {
+                        let lut = match contrast {
+                            Some(c) => gamma_contrast_lut(*gamma, *c),
+                            None => gamma_lut(*gamma),
+                        };
+                        boss_variant(&upscaled, &lut)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_death_preprocess, bench_boss_variants);
+criterion_main!(benches);
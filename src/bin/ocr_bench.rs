@@ -0,0 +1,41 @@
+// src/bin/ocr_bench.rs - Banc d'essai de la détection sur un corpus annoté
+//
+// `cargo run --release --bin ocr_bench -- <manifest.json> [--baseline <fichier>]
+//  [--save-baseline <fichier>]`
+
+use elden_ring_compteur::ocr::bench::run_bench_cli;
+use elden_ring_compteur::structs::settings::settings::Settings;
+use elden_ring_compteur::utils::logging;
+use std::path::Path;
+
+fn main() {
+    // Le garde vide le fichier journal à la fin du programme
+    let log_guard = logging::init(Settings::load().get_log_level());
+
+    let args: Vec<String> = std::env::args().collect();
+    let option = |name: &str| {
+        args.iter()
+            .position(|a| a == name)
+            .and_then(|i| args.get(i + 1))
+            .map(Path::new)
+    };
+    let result = args
+        .get(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .ok_or(
+            "Usage : ocr_bench <manifest.json> [--baseline <fichier>] [--save-baseline <fichier>]"
+                .to_string(),
+        )
+        .and_then(|manifest| {
+            run_bench_cli(
+                Path::new(manifest),
+                option("--baseline"),
+                option("--save-baseline"),
+            )
+        });
+    if let Err(e) = result {
+        tracing::error!("❌ {}", e);
+        drop(log_guard);
+        std::process::exit(1);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::hotkey::{GlobalHotkey, HotkeyError, HotkeyMessage, Key, Modifier};
 
 use tokio::sync::mpsc::UnboundedSender;
+use tracing::{debug, error, info};
 use windows::Win32::UI::Input::KeyboardAndMouse::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
@@ -40,11 +41,11 @@ impl GlobalHotkey for WindowsHotkey {
         unsafe {
             match RegisterHotKey(None, 1, win_mods, vk) {
                 Ok(_) => {
-                    println!("Hotkey registered successfully");
+                    info!("Hotkey registered successfully");
                     Ok(())
                 }
                 Err(e) => {
-                    eprintln!("Register failed: {:?}", e);
+                    error!("Register failed: {:?}", e);
                     Err(HotkeyError::RegistrationFailed)
                 }
             }
@@ -54,21 +55,22 @@ impl GlobalHotkey for WindowsHotkey {
     fn event_loop(&self) {
         unsafe {
             let mut msg = MSG::default();
-            println!("Starting hotkey event loop...");
+            debug!("Starting hotkey event loop...");
 
             while GetMessageW(&mut msg, None, 0, 0).into() {
                 if msg.message == WM_HOTKEY {
+                    debug!("⌨️ WM_HOTKEY reçu");
                     // Envoie le message via le channel tokio
                     // let _ = output
 
                     if let Err(e) = self.sender.send(HotkeyMessage::Increment) {
-                        eprintln!("Failed to send hotkey message: {:?}", e);
+                        error!("Failed to send hotkey message: {:?}", e);
                         break;
                     }
                 }
             }
 
-            println!("Hotkey event loop ended");
+            debug!("Hotkey event loop ended");
         }
     }
 }
@@ -15,6 +15,15 @@ impl fmt::Display for Language {
         }
     }
 }
+impl Language {
+    /// Code de langue des textes de mort prédéfinis (`DeathTextPreset::locale`)
+    pub fn locale(&self) -> &'static str {
+        match self {
+            Language::French => "FR",
+            Language::English => "EN",
+        }
+    }
+}
 impl PartialEq for Language {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -48,6 +48,18 @@ impl I18n {
     pub fn add_recorder(&self, key: AddRecorderKey) -> &'static str {
         self.get(TranslationKey::AddRecorder(key))
     }
+    pub fn vod(&self, key: VodKey) -> &'static str {
+        self.get(TranslationKey::Vod(key))
+    }
+    pub fn runs(&self, key: RunsKey) -> &'static str {
+        self.get(TranslationKey::Runs(key))
+    }
+    pub fn zone_editor(&self, key: ZoneEditorKey) -> &'static str {
+        self.get(TranslationKey::ZoneEditor(key))
+    }
+    pub fn logs(&self, key: LogsKey) -> &'static str {
+        self.get(TranslationKey::Logs(key))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -57,12 +69,17 @@ pub enum TranslationKey {
     Ocr(OcrKey),
     List(ListKey),
     AddRecorder(AddRecorderKey),
+    Vod(VodKey),
+    Runs(RunsKey),
+    ZoneEditor(ZoneEditorKey),
+    Logs(LogsKey),
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum AddRecorderKey {
     Title,
     InputPlaceholder,
+    EncounterBosses,
     AddCounter,
     Cancel,
 }
@@ -73,6 +90,8 @@ pub enum GeneralKey {
     AddRecorder,
     Save,
     Settings,
+    AnalyzeVod,
+    Back,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -83,6 +102,18 @@ pub enum OcrKey {
     SearchingDeath,
     Stopped,
     AutoDetection,
+    ExportSession,
+    GameDetected,
+    Error,
+    HealthLastSuccess,
+    HealthNoSuccess,
+    HealthScanRate,
+    HealthTargetRate,
+    HealthCpu,
+    HealthStages,
+    HealthReduced,
+    HealthFailures,
+    HealthLastError,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -92,13 +123,109 @@ pub enum SettingsKey {
     Screen,
     DeathText,
     DeathTextInput,
+    CaptureMode,
+    CaptureModeMonitor,
+    CaptureModeWindow,
+    CaptureModeImageFolder,
+    CaptureModeVideoFile,
+    CaptureModeStdin,
+    Window,
+    WindowInput,
+    SourcePath,
+    SourcePathInput,
+    FrameSize,
+    AutoDetectGame,
+    AutoStartOcr,
+    CpuBudget,
+    LogLevel,
+    LogLevelError,
+    LogLevelWarn,
+    LogLevelInfo,
+    LogLevelDebug,
+    LogLevelTrace,
+    ShowLogs,
+    DeathTextPresets,
+    DeathGlyphOnly,
+    EditZones,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LogsKey {
+    Title,
+    Folder,
+    Empty,
+    Follow,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum VodKey {
+    Title,
+    PathInput,
+    Analyze,
+    Cancel,
+    Analyzing,
+    DeathsFound,
+    NoBoss,
+    Import,
+    Export,
+    Exported,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RunsKey {
+    Title,
+    NoRun,
+    StartRun,
+    Nightlord,
+    UnknownNightlord,
+    Deaths,
+    NightBosses,
+    Victory,
+    Defeat,
+    Abandon,
+    Summary,
+    RecentRuns,
+    Runs,
+    Victories,
+    DeathsPerRun,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ZoneEditorKey {
+    Title,
+    Capture,
+    Load,
+    ImagePathInput,
+    NoScreenshot,
+    DrawHint,
+    DeathZone,
+    BossZone,
+    AddBossZone,
+    RemoveBossZone,
+    Preview,
+    OcrText,
+    Reset,
+    Presets,
+    PresetPlaceholder,
+    ApplyPreset,
+    SuggestedPreset,
+    PresetNameInput,
+    SavePreset,
+    PresetFileInput,
+    ImportPresets,
+    ExportPresets,
+    PresetsImported,
+    PresetsExported,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum ListKey {
     InputTextPlaceholder,
     TitleGlobalDeaths,
+    Attributing,
     TitleGlobalCounter,
+    BestAttempt,
+    HpLeft,
 }
 
 impl TranslationKey {
@@ -109,6 +236,8 @@ impl TranslationKey {
                 GeneralKey::Save => "Enregistrer",
                 GeneralKey::Settings => "Paramètres",
                 GeneralKey::AddRecorder => "Ajouter un enregistreur",
+                GeneralKey::AnalyzeVod => "Analyser une VOD",
+                GeneralKey::Back => "Retour",
             },
             TranslationKey::Settings(key) => match key {
                 SettingsKey::Language => "Langue",
@@ -116,6 +245,32 @@ impl TranslationKey {
                 SettingsKey::Screen => "Écran",
                 SettingsKey::DeathText => "Texte de mort",
                 SettingsKey::DeathTextInput => "Texte de Mort",
+                SettingsKey::CaptureMode => "Capture",
+                SettingsKey::CaptureModeMonitor => "Écran entier",
+                SettingsKey::CaptureModeWindow => "Fenêtre du jeu",
+                SettingsKey::CaptureModeImageFolder => "Dossier d'images",
+                SettingsKey::CaptureModeVideoFile => "Fichier vidéo (ffmpeg)",
+                SettingsKey::CaptureModeStdin => "Images brutes (stdin)",
+                SettingsKey::Window => "Fenêtre",
+                SettingsKey::WindowInput => "Titre ou processus de la fenêtre",
+                SettingsKey::SourcePath => "Chemin",
+                SettingsKey::SourcePathInput => "Chemin du dossier ou de la vidéo",
+                SettingsKey::FrameSize => "Taille d'image",
+                SettingsKey::AutoDetectGame => "Détecter le jeu lancé",
+                SettingsKey::AutoStartOcr => "Lancer l'OCR avec le jeu",
+                SettingsKey::CpuBudget => "Budget CPU",
+                SettingsKey::LogLevel => "Journal",
+                SettingsKey::LogLevelError => "Erreurs",
+                SettingsKey::LogLevelWarn => "Avertissements",
+                SettingsKey::LogLevelInfo => "Informations",
+                SettingsKey::LogLevelDebug => "Débogage",
+                SettingsKey::LogLevelTrace => "Détaillé (durées OCR)",
+                SettingsKey::ShowLogs => "Voir le journal",
+                SettingsKey::DeathTextPresets => "Textes acceptés",
+                SettingsKey::DeathGlyphOnly => {
+                    "Aucun texte : l'idéogramme 死 est reconnu par sa forme"
+                }
+                SettingsKey::EditZones => "Modifier les zones de détection",
             },
 
             TranslationKey::Ocr(key) => match key {
@@ -125,18 +280,97 @@ impl TranslationKey {
                 OcrKey::SearchingDeath => "Recherche de ta mort... ça arrive",
                 OcrKey::Stopped => "OCR arrêté",
                 OcrKey::AutoDetection => "OCR Auto-détection :",
+                OcrKey::ExportSession => "Exporter les morts de la session",
+                OcrKey::GameDetected => "Jeu détecté :",
+                OcrKey::Error => "Erreur OCR, nouvelle tentative",
+                OcrKey::HealthLastSuccess => "Dernier scan réussi il y a",
+                OcrKey::HealthNoSuccess => "Aucun scan réussi",
+                OcrKey::HealthScanRate => "scans/s",
+                OcrKey::HealthTargetRate => "visés",
+                OcrKey::HealthCpu => "CPU",
+                OcrKey::HealthStages => "Capture / mort / boss :",
+                OcrKey::HealthReduced => "Prétraitement réduit (budget CPU)",
+                OcrKey::HealthFailures => "Échecs consécutifs (capture / OCR) :",
+                OcrKey::HealthLastError => "Dernière erreur :",
             },
             TranslationKey::List(key) => match key {
                 ListKey::InputTextPlaceholder => "Entrer le titre",
                 ListKey::TitleGlobalDeaths => "Morts Totales",
+                ListKey::Attributing => "Attribution du boss…",
                 ListKey::TitleGlobalCounter => "VS Boss",
+                ListKey::BestAttempt => "Meilleure tentative",
+                ListKey::HpLeft => "de PV restants",
             },
             TranslationKey::AddRecorder(key) => match key {
                 AddRecorderKey::Title => "Ajouter un enregistreur",
                 AddRecorderKey::InputPlaceholder => "Titre",
+                AddRecorderKey::EncounterBosses => {
+                    "Boss de la rencontre, séparés par ; (optionnel)"
+                }
                 AddRecorderKey::AddCounter => "Ajouter",
                 AddRecorderKey::Cancel => "Annuler",
             },
+            TranslationKey::Vod(key) => match key {
+                VodKey::Title => "Analyse d'une VOD",
+                VodKey::PathInput => "Chemin du fichier vidéo",
+                VodKey::Analyze => "Analyser",
+                VodKey::Cancel => "Annuler",
+                VodKey::Analyzing => "Analyse en cours...",
+                VodKey::DeathsFound => "mort(s) trouvée(s)",
+                VodKey::NoBoss => "Hors boss",
+                VodKey::Import => "Importer dans les compteurs",
+                VodKey::Export => "Exporter chapitres / EDL / CSV",
+                VodKey::Exported => "Exporté :",
+            },
+            TranslationKey::Runs(key) => match key {
+                RunsKey::Title => "Expédition en cours",
+                RunsKey::NoRun => "Aucune expédition en cours",
+                RunsKey::StartRun => "Nouvelle expédition",
+                RunsKey::Nightlord => "Seigneur de la nuit",
+                RunsKey::UnknownNightlord => "Inconnu",
+                RunsKey::Deaths => "Morts",
+                RunsKey::NightBosses => "Boss de nuit",
+                RunsKey::Victory => "Victoire",
+                RunsKey::Defeat => "Échec",
+                RunsKey::Abandon => "Abandon",
+                RunsKey::Summary => "Bilan par seigneur de la nuit",
+                RunsKey::RecentRuns => "Dernières expéditions",
+                RunsKey::Runs => "expédition(s)",
+                RunsKey::Victories => "victoire(s)",
+                RunsKey::DeathsPerRun => "morts / expédition",
+            },
+            TranslationKey::ZoneEditor(key) => match key {
+                ZoneEditorKey::Title => "Zones de détection",
+                ZoneEditorKey::Capture => "Capturer l'écran",
+                ZoneEditorKey::Load => "Charger",
+                ZoneEditorKey::ImagePathInput => "Chemin d'une capture d'écran",
+                ZoneEditorKey::NoScreenshot => "Capture ou charge une image du jeu",
+                ZoneEditorKey::DrawHint => "Glisse sur l'image pour tracer la zone sélectionnée",
+                ZoneEditorKey::DeathZone => "Mort",
+                ZoneEditorKey::BossZone => "Boss",
+                ZoneEditorKey::AddBossZone => "+ Boss",
+                ZoneEditorKey::RemoveBossZone => "- Boss",
+                ZoneEditorKey::Preview => "Aperçu prétraité",
+                ZoneEditorKey::OcrText => "Texte lu :",
+                ZoneEditorKey::Reset => "Zones par défaut",
+                ZoneEditorKey::Presets => "Presets",
+                ZoneEditorKey::PresetPlaceholder => "Choisir un preset",
+                ZoneEditorKey::ApplyPreset => "Appliquer",
+                ZoneEditorKey::SuggestedPreset => "Suggéré pour ton écran :",
+                ZoneEditorKey::PresetNameInput => "Nom du preset",
+                ZoneEditorKey::SavePreset => "Enregistrer comme preset",
+                ZoneEditorKey::PresetFileInput => "Fichier de presets (.json)",
+                ZoneEditorKey::ImportPresets => "Importer",
+                ZoneEditorKey::ExportPresets => "Exporter",
+                ZoneEditorKey::PresetsImported => "✅ Presets importés :",
+                ZoneEditorKey::PresetsExported => "✅ Presets exportés :",
+            },
+            TranslationKey::Logs(key) => match key {
+                LogsKey::Title => "Journal",
+                LogsKey::Folder => "Fichiers journaux :",
+                LogsKey::Empty => "Aucun message pour l'instant",
+                LogsKey::Follow => "Suivre",
+            },
         }
     }
 
@@ -147,6 +381,8 @@ impl TranslationKey {
                 GeneralKey::Save => "Save",
                 GeneralKey::Settings => "Settings",
                 GeneralKey::AddRecorder => "Add recorder",
+                GeneralKey::AnalyzeVod => "Analyze a VOD",
+                GeneralKey::Back => "Back",
             },
             TranslationKey::Settings(key) => match key {
                 SettingsKey::Language => "Language",
@@ -154,6 +390,32 @@ impl TranslationKey {
                 SettingsKey::DeathText => "Death text",
                 SettingsKey::Game => "Game",
                 SettingsKey::Screen => "Screen",
+                SettingsKey::CaptureMode => "Capture",
+                SettingsKey::CaptureModeMonitor => "Whole screen",
+                SettingsKey::CaptureModeWindow => "Game window",
+                SettingsKey::CaptureModeImageFolder => "Image folder",
+                SettingsKey::CaptureModeVideoFile => "Video file (ffmpeg)",
+                SettingsKey::CaptureModeStdin => "Raw frames (stdin)",
+                SettingsKey::Window => "Window",
+                SettingsKey::WindowInput => "Window title or process",
+                SettingsKey::SourcePath => "Path",
+                SettingsKey::SourcePathInput => "Folder or video path",
+                SettingsKey::FrameSize => "Frame size",
+                SettingsKey::AutoDetectGame => "Detect running game",
+                SettingsKey::AutoStartOcr => "Start OCR with the game",
+                SettingsKey::CpuBudget => "CPU budget",
+                SettingsKey::LogLevel => "Log",
+                SettingsKey::LogLevelError => "Errors",
+                SettingsKey::LogLevelWarn => "Warnings",
+                SettingsKey::LogLevelInfo => "Info",
+                SettingsKey::LogLevelDebug => "Debug",
+                SettingsKey::LogLevelTrace => "Verbose (OCR timings)",
+                SettingsKey::ShowLogs => "Show log",
+                SettingsKey::DeathTextPresets => "Accepted texts",
+                SettingsKey::DeathGlyphOnly => {
+                    "No text: the 死 ideogram is recognised by its shape"
+                }
+                SettingsKey::EditZones => "Edit detection zones",
             },
 
             TranslationKey::Ocr(key) => match key {
@@ -163,18 +425,95 @@ impl TranslationKey {
                 OcrKey::SearchingDeath => "Searching your death...",
                 OcrKey::Stopped => "OCR stopped",
                 OcrKey::AutoDetection => "OCR Auto-detection :",
+                OcrKey::ExportSession => "Export session deaths",
+                OcrKey::GameDetected => "Game detected:",
+                OcrKey::Error => "OCR error, retrying",
+                OcrKey::HealthLastSuccess => "Time since last successful scan:",
+                OcrKey::HealthNoSuccess => "No successful scan yet",
+                OcrKey::HealthScanRate => "scans/s",
+                OcrKey::HealthTargetRate => "targeted",
+                OcrKey::HealthCpu => "CPU",
+                OcrKey::HealthStages => "Capture / death / bosses:",
+                OcrKey::HealthReduced => "Reduced preprocessing (CPU budget)",
+                OcrKey::HealthFailures => "Consecutive failures (capture / OCR):",
+                OcrKey::HealthLastError => "Last error:",
             },
             TranslationKey::List(key) => match key {
                 ListKey::InputTextPlaceholder => "Enter the title",
                 ListKey::TitleGlobalDeaths => "Deaths VS Boss",
+                ListKey::Attributing => "Attributing boss…",
                 ListKey::TitleGlobalCounter => "Global Deaths",
+                ListKey::BestAttempt => "Best attempt",
+                ListKey::HpLeft => "HP left",
             },
             TranslationKey::AddRecorder(key) => match key {
                 AddRecorderKey::Title => "Add Recorder",
                 AddRecorderKey::InputPlaceholder => "Title",
+                AddRecorderKey::EncounterBosses => "Encounter bosses, separated by ; (optional)",
                 AddRecorderKey::AddCounter => "Add",
                 AddRecorderKey::Cancel => "Cancel",
             },
+            TranslationKey::Vod(key) => match key {
+                VodKey::Title => "VOD analysis",
+                VodKey::PathInput => "Video file path",
+                VodKey::Analyze => "Analyze",
+                VodKey::Cancel => "Cancel",
+                VodKey::Analyzing => "Analyzing...",
+                VodKey::DeathsFound => "death(s) found",
+                VodKey::NoBoss => "No boss",
+                VodKey::Import => "Import into recorders",
+                VodKey::Export => "Export chapters / EDL / CSV",
+                VodKey::Exported => "Exported:",
+            },
+            TranslationKey::Runs(key) => match key {
+                RunsKey::Title => "Expedition in progress",
+                RunsKey::NoRun => "No expedition in progress",
+                RunsKey::StartRun => "New expedition",
+                RunsKey::Nightlord => "Nightlord",
+                RunsKey::UnknownNightlord => "Unknown",
+                RunsKey::Deaths => "Deaths",
+                RunsKey::NightBosses => "Night bosses",
+                RunsKey::Victory => "Victory",
+                RunsKey::Defeat => "Failed",
+                RunsKey::Abandon => "Abandoned",
+                RunsKey::Summary => "Summary by nightlord",
+                RunsKey::RecentRuns => "Recent expeditions",
+                RunsKey::Runs => "run(s)",
+                RunsKey::Victories => "win(s)",
+                RunsKey::DeathsPerRun => "deaths / run",
+            },
+            TranslationKey::ZoneEditor(key) => match key {
+                ZoneEditorKey::Title => "Detection zones",
+                ZoneEditorKey::Capture => "Capture screen",
+                ZoneEditorKey::Load => "Load",
+                ZoneEditorKey::ImagePathInput => "Screenshot path",
+                ZoneEditorKey::NoScreenshot => "Capture or load a game screenshot",
+                ZoneEditorKey::DrawHint => "Drag on the image to draw the selected zone",
+                ZoneEditorKey::DeathZone => "Death",
+                ZoneEditorKey::BossZone => "Boss",
+                ZoneEditorKey::AddBossZone => "+ Boss",
+                ZoneEditorKey::RemoveBossZone => "- Boss",
+                ZoneEditorKey::Preview => "Preprocessed preview",
+                ZoneEditorKey::OcrText => "OCR text:",
+                ZoneEditorKey::Reset => "Default zones",
+                ZoneEditorKey::Presets => "Presets",
+                ZoneEditorKey::PresetPlaceholder => "Choose a preset",
+                ZoneEditorKey::ApplyPreset => "Apply",
+                ZoneEditorKey::SuggestedPreset => "Suggested for your monitor:",
+                ZoneEditorKey::PresetNameInput => "Preset name",
+                ZoneEditorKey::SavePreset => "Save as preset",
+                ZoneEditorKey::PresetFileInput => "Preset file (.json)",
+                ZoneEditorKey::ImportPresets => "Import",
+                ZoneEditorKey::ExportPresets => "Export",
+                ZoneEditorKey::PresetsImported => "✅ Presets imported:",
+                ZoneEditorKey::PresetsExported => "✅ Presets exported:",
+            },
+            TranslationKey::Logs(key) => match key {
+                LogsKey::Title => "Log",
+                LogsKey::Folder => "Log files:",
+                LogsKey::Empty => "No messages yet",
+                LogsKey::Follow => "Follow",
+            },
         }
     }
 }
@@ -184,6 +523,7 @@ impl From<StatusOCR> for TranslationKey {
         match status {
             StatusOCR::Starting => TranslationKey::Ocr(OcrKey::Starting),
             StatusOCR::Stopped => TranslationKey::Ocr(OcrKey::Stopped),
+            StatusOCR::Error(_) => TranslationKey::Ocr(OcrKey::Error),
             StatusOCR::Started(action) => match action {
                 ActionOCR::SearchingBossName => TranslationKey::Ocr(OcrKey::SearchingBossName),
                 ActionOCR::EndingAction => TranslationKey::Ocr(OcrKey::EndingAction),
@@ -0,0 +1,10 @@
+// lib.rs - Modules de l'application, partagés par le binaire principal, le banc d'essai
+// `ocr_bench` et les benchmarks
+pub mod hotkey;
+pub mod i18n;
+pub mod ocr;
+pub mod screens;
+pub mod structs;
+pub mod style;
+pub mod utils;
+pub mod vod;
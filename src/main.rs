@@ -1,25 +1,31 @@
-#[macro_export]
-macro_rules! lap {
-    ($start:expr, $label:expr) => {{
-        #[cfg(feature = "timing")]
-        {
-            let elapsed = $start.elapsed();
-            println!("{:<30} {:>6} ms", $label, elapsed.as_millis());
-        }
-    }};
-}
+use elden_ring_compteur::structs::app::App;
+use elden_ring_compteur::structs::settings::capture_source::CaptureSource;
+use elden_ring_compteur::structs::settings::settings::Settings;
+use elden_ring_compteur::utils::headless::run_headless;
+use elden_ring_compteur::utils::logging;
 use iced;
 use iced::{Size, window};
-mod hotkey;
-mod i18n;
-mod ocr;
-mod screens;
-mod structs;
-mod style;
-mod utils;
-use crate::structs::app::App;
 
 fn main() -> iced::Result {
+    // Le garde vide le fichier journal à la fin du programme
+    let log_guard = logging::init(Settings::load().get_log_level());
+
+    // Mode sans interface : `--headless <type:valeur>`
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(pos) = args.iter().position(|a| a == "--headless") {
+        let result = args
+            .get(pos + 1)
+            .ok_or("Source manquante après --headless".to_string())
+            .and_then(|arg| CaptureSource::from_arg(arg))
+            .and_then(run_headless);
+        if let Err(e) = result {
+            tracing::error!("❌ {}", e);
+            drop(log_guard);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     iced::application(App::new, App::update, App::view)
         .subscription(App::subscription)
         .window(window::Settings {
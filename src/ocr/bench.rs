@@ -0,0 +1,354 @@
+// bench.rs - Banc d'essai de la détection sur un corpus de captures annotées
+//
+// Usage : `cargo run --bin ocr_bench -- corpus/manifest.json [--baseline baseline.json]
+//          [--save-baseline baseline.json]`
+//
+// Manifest :
+// {
+//   "death_text": "VOUS AVEZ PERI",            (optionnel, sinon les paramètres)
+//   "frames": [
+//     { "path": "death_margit.png", "death": true, "bosses": ["Margit, le Déchu"] },
+//     { "path": "exploration_01.png", "death": false }
+//   ]
+// }
+// Les chemins des images sont relatifs au dossier du manifest.
+
+use crate::ocr::ocr::{detect_death, get_boss_names};
+use crate::structs::settings::crop_position::ContentArea;
+use crate::structs::settings::settings::Settings;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use strsim::normalized_levenshtein;
+
+/// Similarité minimale pour considérer un nom de boss comme correct
+const BOSS_MATCH_THRESHOLD: f64 = 0.85;
+/// Baisse tolérée de précision / rappel / exactitude avant de signaler une régression
+const METRIC_TOLERANCE: f64 = 0.01;
+/// Hausse tolérée de la latence moyenne d'une étape (+20%)
+const LATENCY_TOLERANCE: f64 = 1.20;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CorpusManifest {
+    #[serde(default)]
+    pub death_text: Option<String>,
+    pub frames: Vec<LabelledFrame>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LabelledFrame {
+    pub path: PathBuf,
+    pub death: bool,
+    #[serde(default)]
+    pub bosses: Vec<String>,
+}
+
+/// Statistiques de latence d'une étape, en millisecondes
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StageLatency {
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+impl StageLatency {
+    fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let percentile = |p: f64| ms[((ms.len() - 1) as f64 * p).round() as usize];
+        Self {
+            mean_ms: ms.iter().sum::<f64>() / ms.len() as f64,
+            p50_ms: percentile(0.50),
+            p95_ms: percentile(0.95),
+            max_ms: *ms.last().unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BenchReport {
+    pub frames: usize,
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+    pub true_negatives: usize,
+    pub precision: f64,
+    pub recall: f64,
+    /// Part des images avec boss annotés dont tous les noms ont été lus
+    pub boss_accuracy: f64,
+    pub load_latency: StageLatency,
+    pub death_latency: StageLatency,
+    pub boss_latency: StageLatency,
+}
+
+impl BenchReport {
+    fn compute_rates(&mut self) {
+        self.precision = ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        );
+        self.recall = ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        );
+    }
+
+    pub fn print(&self) {
+        println!("──────────── Résultats ({} images)", self.frames);
+        println!(
+            "Mort        TP {:>4}  FP {:>4}  FN {:>4}  TN {:>4}",
+            self.true_positives, self.false_positives, self.false_negatives, self.true_negatives
+        );
+        println!("Précision   {:>6.2} %", self.precision * 100.0);
+        println!("Rappel      {:>6.2} %", self.recall * 100.0);
+        println!("Noms boss   {:>6.2} %", self.boss_accuracy * 100.0);
+        for (label, latency) in [
+            ("Chargement", &self.load_latency),
+            ("detect_death", &self.death_latency),
+            ("get_boss_names", &self.boss_latency),
+        ] {
+            println!(
+                "{:<15} moy {:>7.1} ms  p50 {:>7.1} ms  p95 {:>7.1} ms  max {:>7.1} ms",
+                label, latency.mean_ms, latency.p50_ms, latency.p95_ms, latency.max_ms
+            );
+        }
+    }
+
+    /// Régressions par rapport à une baseline enregistrée (vide si aucune)
+    pub fn regressions(&self, baseline: &BenchReport) -> Vec<String> {
+        let mut regressions = Vec::new();
+
+        for (label, current, previous) in [
+            ("précision", self.precision, baseline.precision),
+            ("rappel", self.recall, baseline.recall),
+            ("noms boss", self.boss_accuracy, baseline.boss_accuracy),
+        ] {
+            if current + METRIC_TOLERANCE < previous {
+                regressions.push(format!(
+                    "{} : {:.2} % -> {:.2} %",
+                    label,
+                    previous * 100.0,
+                    current * 100.0
+                ));
+            }
+        }
+
+        for (label, current, previous) in [
+            ("detect_death", &self.death_latency, &baseline.death_latency),
+            ("get_boss_names", &self.boss_latency, &baseline.boss_latency),
+        ] {
+            if previous.mean_ms > 0.0 && current.mean_ms > previous.mean_ms * LATENCY_TOLERANCE {
+                regressions.push(format!(
+                    "latence {} : {:.1} ms -> {:.1} ms",
+                    label, previous.mean_ms, current.mean_ms
+                ));
+            }
+        }
+
+        regressions
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Tous les noms attendus sont retrouvés (ordre indifférent, tolérance OCR)
+fn bosses_match(expected: &[String], found: &[String]) -> bool {
+    if expected.len() != found.len() {
+        return false;
+    }
+    expected.iter().all(|name| {
+        let name = name.trim().to_uppercase();
+        found.iter().any(|f| {
+            normalized_levenshtein(&name, &f.trim().to_uppercase()) >= BOSS_MATCH_THRESHOLD
+        })
+    })
+}
+
+pub fn load_manifest(path: &Path) -> Result<CorpusManifest, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Erreur lecture manifest '{}': {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Manifest invalide: {}", e))
+}
+
+/// Exécuter le pipeline sur tout le corpus
+pub async fn run_bench(manifest_path: &Path) -> Result<BenchReport, String> {
+    let manifest = load_manifest(manifest_path)?;
+    let corpus_dir = manifest_path.parent().unwrap_or(Path::new("."));
+
+    let settings = Settings::load();
+    let game_config = settings.get_game_config();
+    let death_texts = manifest
+        .death_text
+        .clone()
+        .map(|text| vec![text])
+        .unwrap_or_else(|| settings.get_death_texts());
+
+    let mut report = BenchReport {
+        frames: manifest.frames.len(),
+        ..Default::default()
+    };
+    let mut load_samples = Vec::new();
+    let mut death_samples = Vec::new();
+    let mut boss_samples = Vec::new();
+    let mut boss_frames = 0;
+    let mut boss_correct = 0;
+
+    for frame in &manifest.frames {
+        let path = corpus_dir.join(&frame.path);
+
+        let t = Instant::now();
+        let image = image::open(&path)
+            .map_err(|e| format!("Erreur lecture image '{}': {}", path.display(), e))?;
+        load_samples.push(t.elapsed());
+
+        let t = Instant::now();
+        let content = ContentArea::of(&image);
+        let detected = detect_death(
+            &image,
+            content,
+            game_config.get_death_zone(),
+            game_config.get_color_profile(),
+            &death_texts,
+        )
+        .await
+        .unwrap_or(false);
+        death_samples.push(t.elapsed());
+
+        match (frame.death, detected) {
+            (true, true) => report.true_positives += 1,
+            (false, true) => {
+                report.false_positives += 1;
+                println!("❌ Faux positif : {}", frame.path.display());
+            }
+            (true, false) => {
+                report.false_negatives += 1;
+                println!("❌ Mort manquée : {}", frame.path.display());
+            }
+            (false, false) => report.true_negatives += 1,
+        }
+
+        // Les noms sont évalués indépendamment de la détection de mort
+        if !frame.bosses.is_empty() {
+            let t = Instant::now();
+            let found = get_boss_names(image, content, game_config.get_boss_zones().clone())
+                .await
+                .unwrap_or_default();
+            boss_samples.push(t.elapsed());
+
+            boss_frames += 1;
+            if bosses_match(&frame.bosses, &found) {
+                boss_correct += 1;
+            } else {
+                println!(
+                    "❌ Boss {} : attendu {:?}, lu {:?}",
+                    frame.path.display(),
+                    frame.bosses,
+                    found
+                );
+            }
+        }
+    }
+
+    report.compute_rates();
+    report.boss_accuracy = ratio(boss_correct, boss_frames);
+    report.load_latency = StageLatency::from_samples(&load_samples);
+    report.death_latency = StageLatency::from_samples(&death_samples);
+    report.boss_latency = StageLatency::from_samples(&boss_samples);
+    Ok(report)
+}
+
+pub fn load_report(path: &Path) -> Result<BenchReport, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Erreur lecture baseline '{}': {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Baseline invalide: {}", e))
+}
+
+pub fn save_report(report: &BenchReport, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    std::fs::write(path, json)
+        .map_err(|e| format!("Erreur écriture baseline '{}': {}", path.display(), e))
+}
+
+/// Point d'entrée `--bench` : renvoie une erreur en cas de régression
+pub fn run_bench_cli(
+    manifest: &Path,
+    baseline: Option<&Path>,
+    save_baseline: Option<&Path>,
+) -> Result<(), String> {
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("Erreur runtime: {}", e))?;
+    let report = runtime.block_on(run_bench(manifest))?;
+    report.print();
+
+    if let Some(path) = save_baseline {
+        save_report(&report, path)?;
+        println!("💾 Baseline enregistrée : {}", path.display());
+    }
+
+    if let Some(path) = baseline {
+        let regressions = report.regressions(&load_report(path)?);
+        if !regressions.is_empty() {
+            return Err(format!("Régressions :\n  {}", regressions.join("\n  ")));
+        }
+        println!("✅ Aucune régression par rapport à {}", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bosses_match_ignores_order_and_small_ocr_errors() {
+        let expected = vec!["Margit, le Déchu".to_string(), "Godrick".to_string()];
+
+        assert!(bosses_match(
+            &expected,
+            &["GODRICK".to_string(), "Margit, le Dechu".to_string()]
+        ));
+        assert!(!bosses_match(&expected, &["Margit, le Déchu".to_string()]));
+    }
+
+    #[test]
+    fn regressions_flag_metric_drops_and_slower_stages() {
+        let baseline = BenchReport {
+            precision: 0.95,
+            recall: 0.90,
+            boss_accuracy: 0.80,
+            death_latency: StageLatency {
+                mean_ms: 100.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut current = baseline.clone();
+        assert!(current.regressions(&baseline).is_empty());
+
+        current.recall = 0.85;
+        current.death_latency.mean_ms = 150.0;
+        assert_eq!(current.regressions(&baseline).len(), 2);
+    }
+
+    #[test]
+    fn stage_latency_percentiles() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        let latency = StageLatency::from_samples(&samples);
+
+        assert_eq!(latency.mean_ms.round(), 51.0);
+        assert_eq!(latency.p50_ms.round(), 51.0);
+        assert_eq!(latency.p95_ms.round(), 95.0);
+        assert_eq!(latency.max_ms.round(), 100.0);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::i18n::translations::AddRecorderKey;
 use crate::structs::app::Screen;
+use crate::structs::encounter::Encounter;
 use crate::structs::recorder::Recorder;
+use crate::structs::settings::settings::Settings;
 use crate::structs::storage::Storage;
 use crate::{i18n::translations::I18n, screens::main_screen::MainScreen};
 use iced::{
@@ -11,6 +13,7 @@ use iced::{
 #[derive(Debug, Clone)]
 pub enum AddRecorderMessage {
     TitleChanged(String),
+    BossesChanged(String),
     AddCounter,
     CancelAddCounter,
     ChangeView(Screen),
@@ -19,6 +22,8 @@ pub enum AddRecorderMessage {
 #[derive(Debug, Clone)]
 pub struct AddRecorderScreen {
     title: String,
+    /// Boss d'une rencontre à plusieurs, séparés par des `;`
+    bosses: String,
     error: Option<String>,
 }
 
@@ -26,6 +31,7 @@ impl AddRecorderScreen {
     pub fn new() -> Self {
         Self {
             title: String::new(),
+            bosses: String::new(),
             error: None,
         }
     }
@@ -35,11 +41,26 @@ impl AddRecorderScreen {
                 self.title = new_title;
                 Task::none()
             }
+            AddRecorderMessage::BossesChanged(bosses) => {
+                self.bosses = bosses;
+                Task::none()
+            }
             AddRecorderMessage::AddCounter => {
                 if self.title.is_empty() {
                     self.error = Some("Title cannot be empty".to_string());
                     return Task::none();
                 }
+                let bosses: Vec<&str> = self
+                    .bosses
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|b| !b.is_empty())
+                    .collect();
+                if bosses.len() > 1 {
+                    let mut settings = Settings::load();
+                    settings.add_encounter(Encounter::new(self.title.trim(), &bosses));
+                    settings.save();
+                }
                 let recorder = Recorder::new(self.title.clone());
                 match Storage::insert_recorder_at_first_position(&recorder) {
                     Ok(_) => Task::done(AddRecorderMessage::ChangeView(Screen::MainScreen(
@@ -70,6 +91,14 @@ impl AddRecorderScreen {
                 .on_submit(AddRecorderMessage::AddCounter)
                 .padding(10)
                 .size(16),
+                text_input(
+                    i18n.add_recorder(AddRecorderKey::EncounterBosses),
+                    &self.bosses
+                )
+                .on_input(AddRecorderMessage::BossesChanged)
+                .on_submit(AddRecorderMessage::AddCounter)
+                .padding(10)
+                .size(16),
                 // ✅ message d'erreur inline
                 if let Some(error) = &self.error {
                     text(error).size(14).color(Color::from_rgb(0.8, 0.0, 0.0))
@@ -2,11 +2,21 @@ use crate::hotkey::HotkeyMessage;
 use crate::i18n::translations::GeneralKey;
 use crate::i18n::translations::I18n;
 use crate::i18n::translations::ListKey;
-use crate::structs::recorder::Recorder;
+use crate::screens::components::ocr::DeathId;
+use crate::structs::boss::find_boss_id;
+use crate::structs::encounter::{Encounter, resolve_encounter, same_fight};
+use crate::structs::recorder::{Attempt, Recorder};
+use crate::structs::settings::settings::Settings;
 use crate::structs::storage::Storage;
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry};
 use iced::widget::{button, column, container, row, scrollable, text, text_input, toggler};
-use iced::{Color, Element, Length, Subscription, Task, time::Duration};
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Subscription, Task, Theme,
+    time::Duration,
+};
 use strsim::normalized_levenshtein;
+use tracing::info;
 use uuid::Uuid;
 
 // -------------------------------------------------------
@@ -31,7 +41,11 @@ pub enum ListMessage {
     ToggleRecorder(uuid::Uuid),
 
     AutosaveTick,
-    OcrDeath(Vec<String>),
+    /// Mort confirmée : comptée tout de suite, les boss sont rattachés à l'arrivée de l'OCR
+    OcrDeath(DeathId),
+    /// Boss lus et PV restants de chaque barre (0.0 – 1.0) juste avant la mort
+    OcrBosses(DeathId, Vec<String>, Vec<f32>),
+    CancelAttributions,
     HotKey(HotkeyMessage),
 }
 
@@ -46,6 +60,10 @@ pub struct ListComponent {
     pub recorders: Vec<Recorder>,
     pub global_recorders: Vec<Recorder>,
     pub dirty: bool,
+    /// Rencontres à plusieurs boss (un seul compteur par combat)
+    encounters: Vec<Encounter>,
+    /// Morts déjà comptées dont les boss sont en cours de lecture
+    pending_attributions: Vec<DeathId>,
 }
 
 impl ListComponent {
@@ -53,7 +71,7 @@ impl ListComponent {
         let (recorders, mut global_recorders) = {
             #[cfg(feature = "no_save")]
             {
-                println!("🐛 Mode DEBUG activé - pas de chargement des données");
+                info!("🐛 Mode DEBUG activé - pas de chargement des données");
                 (Vec::new(), Vec::new())
             }
 
@@ -71,6 +89,8 @@ impl ListComponent {
             recorders,
             global_recorders,
             dirty: false,
+            encounters: Settings::load().get_encounters(),
+            pending_attributions: Vec::new(),
         }
     }
 
@@ -93,26 +113,29 @@ impl ListComponent {
 
     pub fn update(&mut self, message: ListMessage) -> Task<ListMessage> {
         match message {
-            ListMessage::OcrDeath(bosses) => {
+            ListMessage::OcrDeath(death_id) => {
                 self.increment_global_deaths();
-
-                if bosses.is_empty() {
-                    return Task::none();
-                }
-                self.increment_global_bosses();
-                let bosses_names: String = bosses
-                    .into_iter()
-                    .filter(|b| !b.trim().is_empty())
-                    .map(|b| b.trim().to_string())
-                    .collect::<Vec<_>>()
-                    .join(" - ");
-
-                if !bosses_names.is_empty() {
-                    self.handle_boss_death(bosses_names);
-                }
+                self.pending_attributions.push(death_id);
                 self.dirty();
                 Task::none()
             }
+            ListMessage::OcrBosses(death_id, bosses, health) => {
+                // Mort inconnue ou attribution annulée (OCR arrêté entre temps)
+                let Some(pos) = self
+                    .pending_attributions
+                    .iter()
+                    .position(|id| *id == death_id)
+                else {
+                    return Task::none();
+                };
+                self.pending_attributions.remove(pos);
+                self.attribute_bosses(bosses, &health);
+                Task::none()
+            }
+            ListMessage::CancelAttributions => {
+                self.pending_attributions.clear();
+                Task::none()
+            }
             ListMessage::AutosaveTick => {
                 self.save();
                 Task::none()
@@ -192,8 +215,9 @@ impl ListComponent {
 
         let classics = self.recorders.iter().enumerate();
 
+        let attributing = !self.pending_attributions.is_empty();
         let global_elements =
-            globals.map(|(_, recorder)| Self::view_global_recorder(recorder, i18n));
+            globals.map(|(_, recorder)| Self::view_global_recorder(recorder, attributing, i18n));
         let dragging_pos = self.dragging; // Option<usize> - maintenant c'est une position, pas un index
         let classic_elements = classics.enumerate().flat_map(|(pos, (index, recorder))| {
             let is_dragging = dragging_pos == Some(pos);
@@ -262,6 +286,7 @@ impl ListComponent {
     // --- Vue d'un compteur global ---
     fn view_global_recorder<'a>(
         recorder: &'a Recorder,
+        attributing: bool,
         i18n: &'a I18n,
     ) -> Element<'a, ListMessage> {
         let uuid = recorder.get_uuid();
@@ -285,6 +310,14 @@ impl ListComponent {
                 .size(22)
                 .width(Length::Fill)
                 .style(move |_theme| text::Style { color: Some(color) }),
+            // Mort comptée, boss pas encore lu
+            text(if attributing && recorder.is_global_deaths() {
+                format!("⏳ {}", i18n.list(ListKey::Attributing))
+            } else {
+                String::new()
+            })
+            .size(16)
+            .color(Color::from_rgb(0.7, 0.7, 0.7)),
             button(text("⟲").size(18)).on_press(ListMessage::ResetRecorder(*uuid)),
             button("-").on_press(ListMessage::DecrementRecorder(*uuid)),
             button("+").on_press(ListMessage::IncrementRecorder(*uuid)),
@@ -327,7 +360,7 @@ impl ListComponent {
                 .width(Length::Fill)
                 .into()
         } else {
-            button(text(recorder.get_title()).size(20))
+            button(text(recorder.get_display_title(&i18n.language)).size(20))
                 .on_press(ListMessage::StartEditingTitle(*uuid))
                 .padding(0)
                 .style(|_theme, _status| button::Style {
@@ -356,7 +389,34 @@ impl ListComponent {
         ]
         .spacing(20);
 
-        let recorder_container = container(recorder_row)
+        // Meilleure tentative et progression tentative par tentative
+        let recorder_content: Element<'a, ListMessage> = match recorder.best_attempt() {
+            Some(best) => column![
+                recorder_row,
+                row![
+                    text(format!(
+                        "🎯 {} : {:.0} % {}",
+                        i18n.list(ListKey::BestAttempt),
+                        best.hp_left * 100.0,
+                        i18n.list(ListKey::HpLeft)
+                    ))
+                    .size(14)
+                    .width(260)
+                    .color(Color::from_rgb(0.9, 0.75, 0.3)),
+                    Canvas::new(AttemptsChart {
+                        attempts: recorder.get_attempts(),
+                    })
+                    .width(Length::Fill)
+                    .height(ATTEMPTS_CHART_HEIGHT),
+                ]
+                .spacing(20),
+            ]
+            .spacing(10)
+            .into(),
+            None => recorder_row.into(),
+        };
+
+        let recorder_container = container(recorder_content)
             .padding(15)
             .width(Length::Fill)
             .style(if is_dragging {
@@ -403,7 +463,9 @@ impl ListComponent {
         for recorder in self.recorders.iter_mut() {
             recorder.increment();
         }
-        self.global_recorders.iter_mut().for_each(|r| r.increment());
+        self.global_recorders.iter_mut().for_each(|r| {
+            r.increment();
+        });
         self.dirty();
     }
 
@@ -509,37 +571,86 @@ impl ListComponent {
         }
     }
 
-    fn handle_boss_death(&mut self, boss_name: String) {
-        println!("⚔️  Mort contre : {}", boss_name);
+    /// Importer des morts détectées hors-ligne (VOD), une liste de boss par mort
+    pub fn import_deaths(&mut self, deaths: Vec<Vec<String>>) {
+        for bosses in deaths {
+            self.increment_global_deaths();
+            self.attribute_bosses(bosses, &[]);
+        }
+        self.dirty();
+    }
 
-        let normalized_boss = boss_name.trim().to_uppercase();
+    /// Rattacher les boss lus à une mort déjà comptée dans le compteur global
+    fn attribute_bosses(&mut self, bosses: Vec<String>, health: &[f32]) {
+        if bosses.is_empty() {
+            return;
+        }
+        self.increment_global_bosses();
+        let bosses_names = resolve_encounter(&bosses, &self.encounters);
+
+        if !bosses_names.is_empty() {
+            // Un combat à plusieurs boss compte comme une seule tentative
+            let hp_left =
+                (!health.is_empty()).then(|| health.iter().sum::<f32>() / health.len() as f32);
+            self.handle_boss_death(bosses_names, hp_left);
+        }
+        self.dirty();
+    }
 
-        // 1. Chercher correspondance exacte d'abord
-        if let Some(pos) = self
+    fn handle_boss_death(&mut self, boss_name: String, hp_left: Option<f32>) {
+        info!("⚔️  Mort contre : {}", boss_name);
+
+        let normalized_boss = boss_name.trim().to_uppercase();
+        let boss_id = find_boss_id(&boss_name);
+        // Compteur en pause : la mort n'est pas comptée, pas de tentative enregistrée
+        let counted;
+
+        // 0. Même boss du catalogue, quelle que soit la langue du jeu
+        if let Some(pos) = boss_id.and_then(|id| {
+            self.recorders
+                .iter()
+                .position(|r| r.get_boss_id().map(String::as_str) == Some(id))
+        }) {
+            let mut recorder = self.recorders.remove(pos);
+            counted = recorder.increment();
+            self.recorders.insert(0, recorder);
+            info!("✅ Compteur '{}' incrémenté (même boss)", boss_name);
+        // 1. Chercher correspondance exacte
+        } else if let Some(pos) = self
             .recorders
             .iter()
             .position(|r| r.get_title().to_uppercase() == normalized_boss)
         {
             let mut recorder = self.recorders.remove(pos);
-            recorder.increment();
+            counted = recorder.increment();
             self.recorders.insert(0, recorder); // Insérer en première position
-            println!("✅ Compteur '{}' incrémenté (match exact)", boss_name);
+            info!("✅ Compteur '{}' incrémenté (match exact)", boss_name);
+        } else if let Some(pos) = self
+            .recorders
+            .iter()
+            .position(|r| same_fight(&r.get_title(), &boss_name))
+        {
+            // Même combat, boss lus dans un autre ordre
+            let mut recorder = self.recorders.remove(pos);
+            counted = recorder.increment();
+            self.recorders.insert(0, recorder);
+            info!("✅ Compteur '{}' incrémenté (même combat)", boss_name);
         } else {
             // 2. Pas de match exact, chercher une similarité
             let similar = self.find_similar_boss(&normalized_boss, 0.80);
 
             match similar {
                 Some((pos, similarity, existing_name)) => {
-                    println!(
+                    info!(
                         "🔍 Boss similaire trouvé: '{}' ~= '{}' ({}% similaire)",
                         normalized_boss,
                         existing_name,
                         (similarity * 100.0) as u32
                     );
                     let mut recorder = self.recorders.remove(pos);
-                    recorder.increment();
+                    counted = recorder.increment();
                     self.recorders.insert(0, recorder);
-                    println!(
+                    info!(
                         "✅ Compteur '{}' incrémenté (match similaire)",
                         existing_name
                     );
@@ -548,12 +659,17 @@ impl ListComponent {
                     // 3. Pas de match similaire : créer nouveau compteur
                     let mut new_recorder = Recorder::new(boss_name.clone());
                     new_recorder.force_increment();
+                    counted = true;
                     self.recorders.insert(0, new_recorder);
-                    println!("✅ Nouveau compteur '{}' créé", boss_name);
+                    info!("✅ Nouveau compteur '{}' créé", boss_name);
                 }
             }
         }
 
+        // Le compteur du boss vient d'être remis en tête
+        if counted && let (Some(hp_left), Some(recorder)) = (hp_left, self.recorders.first_mut()) {
+            recorder.add_attempt(hp_left);
+        }
         self.dirty = true;
     }
     fn find_similar_boss(&self, boss_name: &str, threshold: f64) -> Option<(usize, f64, String)> {
@@ -582,3 +698,50 @@ impl ListComponent {
         best_match
     }
 }
+
+/// Tentatives affichées dans le graphique (les plus récentes)
+const ATTEMPTS_CHART_MAX: usize = 40;
+const ATTEMPTS_CHART_HEIGHT: f32 = 40.0;
+
+/// Progression par tentative : une barre par mort, hauteur = PV retirés au boss
+struct AttemptsChart<'a> {
+    attempts: &'a [Attempt],
+}
+
+impl canvas::Program<ListMessage> for AttemptsChart<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let recent = &self.attempts[self.attempts.len().saturating_sub(ATTEMPTS_CHART_MAX)..];
+        let best = self
+            .attempts
+            .iter()
+            .map(|a| a.hp_left)
+            .fold(f32::INFINITY, f32::min);
+
+        let slot = bounds.width / ATTEMPTS_CHART_MAX as f32;
+        for (i, attempt) in recent.iter().enumerate() {
+            let height = (1.0 - attempt.hp_left).max(0.02) * bounds.height;
+            let color = if attempt.hp_left <= best {
+                Color::from_rgb(0.9, 0.75, 0.3)
+            } else {
+                Color::from_rgb(0.7, 0.3, 0.3)
+            };
+            frame.fill_rectangle(
+                Point::new(i as f32 * slot, bounds.height - height),
+                Size::new((slot - 2.0).max(1.0), height),
+                color,
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -1,16 +1,27 @@
 use std::fmt;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use iced::{
     Color, Element, Subscription, Task,
-    widget::{column, row, text, toggler},
+    time::Duration,
+    widget::{button, column, row, text, toggler},
 };
 use iced_aw::Spinner;
 
 use crate::{
     i18n::translations::{I18n, OcrKey},
-    structs::settings::settings::Settings,
-    utils::app_worker::ocr_subscription,
+    structs::{
+        settings::{game::Game, settings::Settings},
+        storage::Storage,
+    },
+    utils::{
+        app_worker::ocr_subscription,
+        export::{DeathMarker, export_all},
+        game_detection::{GAME_SCAN_INTERVAL_SECS, detect_running_game},
+        ocr_health::OcrHealth,
+    },
 };
+use tracing::info;
 
 #[derive(Clone, Debug)]
 pub enum ActionOCR {
@@ -35,6 +46,8 @@ impl fmt::Display for ActionOCR {
 pub enum StatusOCR {
     Starting,
     Started(ActionOCR),
+    /// Capture ou OCR en échec, le worker réessaie avec une attente croissante
+    Error(String),
     Stopped,
 }
 
@@ -43,6 +56,7 @@ impl StatusOCR {
         match self {
             StatusOCR::Starting => Color::from_rgb(1.0, 0.65, 0.0),
             StatusOCR::Started(_) => Color::from_rgb(0.0, 0.8, 0.0),
+            StatusOCR::Error(_) => Color::from_rgb(1.0, 0.3, 0.3),
             StatusOCR::Stopped => Color::from_rgb(0.6, 0.6, 0.6),
         }
     }
@@ -62,6 +76,9 @@ impl fmt::Display for StatusOCR {
         let text = match self {
             StatusOCR::Starting => i18n.ocr(OcrKey::Starting),
             StatusOCR::Started(action) => return write!(f, "{action}"),
+            StatusOCR::Error(reason) => {
+                return write!(f, "⚠️ {} : {reason}", i18n.ocr(OcrKey::Error));
+            }
             StatusOCR::Stopped => i18n.ocr(OcrKey::Stopped),
         };
 
@@ -69,12 +86,23 @@ impl fmt::Display for StatusOCR {
     }
 }
 
+/// Numéro unique d'une mort (tous workers confondus), pour lui rattacher les boss lus ensuite
+pub type DeathId = u64;
+
 #[derive(Clone, Debug)]
 pub enum OcrMessage {
     ActivateOCR(bool),
+    /// Un worker OCR (re)démarre : les morts en attente de boss ne seront plus complétées
+    WorkerStarted,
     ChangeActionOCR(StatusOCR),
-    BossesFoundOCR(Vec<String>),
-    DeathDetected,
+    /// Boss lus pour une mort déjà comptée, avec les PV restants (0.0 – 1.0) de chaque
+    /// barre de boss juste avant la mort (vide si les barres n'ont pas été suivies)
+    BossesFoundOCR(DeathId, Vec<String>, Vec<f32>),
+    DeathDetected(DeathId),
+    HealthReport(OcrHealth),
+    ExportSession,
+    ScanRunningGame,
+    GameDetected(Option<Game>),
 }
 
 #[derive(Clone, Debug)]
@@ -82,6 +110,16 @@ pub struct OcrComponent {
     settings: Settings,
     ocr_activate: bool,
     ocr_status: StatusOCR,
+    /// Début de la session OCR en cours (pour horodater les morts)
+    session_start: Option<Instant>,
+    session_deaths: Vec<DeathMarker>,
+    /// Morts de la session dont les boss ne sont pas encore lus (index dans `session_deaths`)
+    pending_markers: Vec<(DeathId, usize)>,
+    export_status: Option<String>,
+    /// Jeu trouvé dans les processus lors du dernier scan
+    detected_game: Option<Game>,
+    /// Dernier rapport de santé du worker
+    health: Option<OcrHealth>,
 }
 impl Default for OcrComponent {
     fn default() -> Self {
@@ -89,6 +127,12 @@ impl Default for OcrComponent {
             settings: Settings::load(),
             ocr_activate: false,
             ocr_status: StatusOCR::Stopped,
+            session_start: None,
+            session_deaths: Vec::new(),
+            pending_markers: Vec::new(),
+            export_status: None,
+            detected_game: None,
+            health: None,
         }
     }
 }
@@ -101,6 +145,14 @@ impl OcrComponent {
     pub fn update(&mut self, message: OcrMessage) -> Task<OcrMessage> {
         match message {
             OcrMessage::ActivateOCR(active) => {
+                // Nouvelle session uniquement au passage arrêté -> actif
+                if active && !self.ocr_activate {
+                    self.session_start = Some(Instant::now());
+                    self.session_deaths.clear();
+                    self.pending_markers.clear();
+                    self.export_status = None;
+                    self.health = None;
+                }
                 if active {
                     self.ocr_status = StatusOCR::Starting;
                 } else {
@@ -111,33 +163,102 @@ impl OcrComponent {
                 Task::none()
             }
 
+            OcrMessage::WorkerStarted => {
+                self.pending_markers.clear();
+                Task::none()
+            }
+
             OcrMessage::ChangeActionOCR(action) => {
                 match action {
                     StatusOCR::Started(action) => {
                         self.set_ocr_action(action);
                     }
+                    StatusOCR::Error(reason) => {
+                        self.ocr_status = StatusOCR::Error(reason);
+                    }
                     _ => {}
                 }
                 Task::none()
             }
-            OcrMessage::BossesFoundOCR(bosses) => {
-                let bosses_names: String = bosses
+            OcrMessage::BossesFoundOCR(death_id, bosses, _) => {
+                let bosses: Vec<String> = bosses
                     .into_iter()
                     .filter(|b| !b.trim().is_empty())
                     .map(|b| b.trim().to_string())
-                    .collect::<Vec<_>>()
-                    .join(" - ");
+                    .collect();
 
-                if !bosses_names.is_empty() {
-                    //self.handle_boss_death(bosses_names);
+                // Rattacher les boss à la mort qui a lancé la lecture
+                if let Some(pos) = self
+                    .pending_markers
+                    .iter()
+                    .position(|(id, _)| *id == death_id)
+                {
+                    let (_, index) = self.pending_markers.remove(pos);
+                    if let Some(marker) = self.session_deaths.get_mut(index) {
+                        marker.bosses = bosses;
+                    }
                 }
                 Task::none()
             }
-            OcrMessage::DeathDetected => {
-                println!("💀 Mort détectée ! Recherche des boss...");
-                //self.list.increment_global_deaths();
+            OcrMessage::DeathDetected(death_id) => {
+                info!("💀 Mort détectée ! Recherche des boss...");
+                let timestamp = self
+                    .session_start
+                    .map(|start| start.elapsed())
+                    .unwrap_or_default();
+                self.pending_markers
+                    .push((death_id, self.session_deaths.len()));
+                self.session_deaths
+                    .push(DeathMarker::new(timestamp, Vec::new()));
+                Task::none()
+            }
+            OcrMessage::HealthReport(health) => {
+                self.health = Some(health);
+                Task::none()
+            }
+            OcrMessage::ExportSession => {
+                self.export_status = Some(match self.export_session() {
+                    Ok(path) => path,
+                    Err(e) => e,
+                });
                 Task::none()
             }
+            OcrMessage::ScanRunningGame => Task::perform(
+                async {
+                    tokio::task::spawn_blocking(detect_running_game)
+                        .await
+                        .unwrap_or(None)
+                },
+                OcrMessage::GameDetected,
+            ),
+            OcrMessage::GameDetected(game) => {
+                let previous = self.detected_game;
+                self.detected_game = game;
+
+                // Mauvais jeu sélectionné : basculer (zones, texte de mort...)
+                if let Some(game) = game
+                    && self.settings.get_auto_detect_game()
+                    && game != self.settings.get_game()
+                {
+                    info!("🎮 Jeu détecté : {}", game);
+                    self.settings = Settings::load();
+                    self.settings.switch_game(game);
+                    self.settings.save();
+                }
+
+                if !self.settings.get_auto_start_ocr() {
+                    return Task::none();
+                }
+                match (previous, game) {
+                    (None, Some(_)) if !self.ocr_activate => {
+                        Task::done(OcrMessage::ActivateOCR(true))
+                    }
+                    (Some(_), None) if self.ocr_activate => {
+                        Task::done(OcrMessage::ActivateOCR(false))
+                    }
+                    _ => Task::none(),
+                }
+            }
         }
     }
 
@@ -156,24 +277,155 @@ impl OcrComponent {
                 self.ocr_status.spinner_element()
             ]
             .spacing(10),
+            row![
+                if self.session_deaths.is_empty() {
+                    button(i18n.ocr(OcrKey::ExportSession))
+                } else {
+                    button(i18n.ocr(OcrKey::ExportSession)).on_press(OcrMessage::ExportSession)
+                },
+                text(self.export_status.clone().unwrap_or_default()).size(14)
+            ]
+            .spacing(10),
+            text(match self.detected_game {
+                Some(game) => format!("🎮 {} {}", i18n.ocr(OcrKey::GameDetected), game),
+                None => String::new(),
+            })
+            .size(14),
+            self.view_health(i18n),
         ]
         .spacing(10)
         .into()
     }
 
+    /// Santé du worker : dernier scan réussi, fréquence, échecs et dernière erreur
+    fn view_health(&self, i18n: &I18n) -> Element<'_, OcrMessage> {
+        let Some(health) = self.health.as_ref().filter(|_| self.ocr_activate) else {
+            return column![].into();
+        };
+
+        let last_success = match health.last_success {
+            Some(instant) => format!(
+                "{} {:.1} s",
+                i18n.ocr(OcrKey::HealthLastSuccess),
+                instant.elapsed().as_secs_f32()
+            ),
+            None => i18n.ocr(OcrKey::HealthNoSuccess).to_string(),
+        };
+        let failures_color = if health.is_failing() {
+            Color::from_rgb(1.0, 0.3, 0.3)
+        } else {
+            Color::from_rgb(0.6, 0.6, 0.6)
+        };
+
+        let mut panel = column![
+            row![
+                text(last_success).size(13),
+                text(format!(
+                    "{:.1} {}",
+                    health.scan_rate,
+                    i18n.ocr(OcrKey::HealthScanRate)
+                ))
+                .size(13),
+                text(format!(
+                    "{} {} / {}",
+                    i18n.ocr(OcrKey::HealthFailures),
+                    health.capture_failures,
+                    health.ocr_failures
+                ))
+                .size(13)
+                .color(failures_color),
+            ]
+            .spacing(20),
+        ]
+        .spacing(5);
+        if let Some(budget) = &health.budget {
+            panel = panel.push(
+                row![
+                    text(format!(
+                        "{:.1} {} {}",
+                        budget.target_rate,
+                        i18n.ocr(OcrKey::HealthScanRate),
+                        i18n.ocr(OcrKey::HealthTargetRate)
+                    ))
+                    .size(13),
+                    text(format!(
+                        "{} {:.0} % / {:.0} %",
+                        i18n.ocr(OcrKey::HealthCpu),
+                        budget.cpu_usage * 100.0,
+                        budget.cpu_budget * 100.0
+                    ))
+                    .size(13),
+                    text(format!(
+                        "{} {} / {} / {} ms",
+                        i18n.ocr(OcrKey::HealthStages),
+                        budget.timings.capture.as_millis(),
+                        budget.timings.death.as_millis(),
+                        budget.timings.bosses.as_millis()
+                    ))
+                    .size(13),
+                ]
+                .spacing(20),
+            );
+            if budget.reduced {
+                panel = panel.push(
+                    text(i18n.ocr(OcrKey::HealthReduced))
+                        .size(13)
+                        .color(Color::from_rgb(1.0, 0.7, 0.3)),
+                );
+            }
+        }
+        if let Some(error) = &health.last_error {
+            panel = panel.push(
+                text(format!("{} {}", i18n.ocr(OcrKey::HealthLastError), error))
+                    .size(13)
+                    .color(Color::from_rgb(0.8, 0.5, 0.5)),
+            );
+        }
+        panel.into()
+    }
+
     pub fn subscription(&self) -> Subscription<OcrMessage> {
         // ✅ Conditionnellement créer la subscription OCR
         let ocr_sub = if self.ocr_activate {
             ocr_subscription(
-                self.settings.get_screen(),
+                self.settings.get_capture_source(),
                 self.settings.get_game_config(),
-                self.settings.get_death_text().clone(),
+                self.settings.get_death_texts(),
+                self.settings.get_cpu_budget(),
             )
         } else {
             Subscription::none()
         };
 
-        Subscription::batch(vec![ocr_sub])
+        // Scan périodique des processus pour détecter le jeu lancé
+        let scan_sub = if self.settings.get_auto_detect_game() || self.settings.get_auto_start_ocr()
+        {
+            iced::time::every(Duration::from_secs(GAME_SCAN_INTERVAL_SECS))
+                .map(|_| OcrMessage::ScanRunningGame)
+        } else {
+            Subscription::none()
+        };
+
+        Subscription::batch(vec![ocr_sub, scan_sub])
+    }
+
+    /// Exporter les morts de la session dans `<data_dir>/exports/session_<timestamp>`
+    fn export_session(&self) -> Result<String, String> {
+        let dir = Storage::get_data_dir()?.join("exports");
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let base = dir.join(format!("session_{}", started_at));
+
+        export_all(&self.session_deaths, &base)?;
+        Ok(dir.display().to_string())
+    }
+
+    pub fn get_game(&self) -> Game {
+        self.settings.get_game()
     }
 
     pub fn set_ocr_active(&mut self, active: bool) {
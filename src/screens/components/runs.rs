@@ -0,0 +1,266 @@
+use crate::i18n::translations::{I18n, RunsKey};
+use crate::screens::components::ocr::DeathId;
+use crate::structs::run::{NIGHTLORDS, Run, RunOutcome, summarize_runs};
+use crate::structs::storage::Storage;
+use crate::vod::vod::format_timestamp;
+use iced::widget::{button, column, container, pick_list, row, scrollable, text};
+use iced::{Alignment, Color, Element, Length, time::Duration};
+use tracing::error;
+
+/// Nombre d'expéditions terminées affichées sous le bilan
+const RECENT_RUNS: usize = 10;
+
+// -------------------------------------------------------
+// Messages propres aux expéditions (jeux en runs : Nightreign)
+// -------------------------------------------------------
+#[derive(Debug, Clone)]
+pub enum RunsMessage {
+    StartRun,
+    EndRun(RunOutcome),
+    NightlordSelected(String),
+    OcrDeath(DeathId),
+    OcrBosses(DeathId, Vec<String>, Vec<f32>),
+    CancelAttributions,
+}
+
+// -------------------------------------------------------
+// État : expédition en cours + historique
+// -------------------------------------------------------
+#[derive(Clone, Debug, Default)]
+pub struct RunsComponent {
+    current: Option<Run>,
+    /// Expéditions terminées, de la plus récente à la plus ancienne
+    history: Vec<Run>,
+    /// Morts comptées dont les boss sont en cours de lecture (index dans l'expédition en cours)
+    pending_attributions: Vec<(DeathId, usize)>,
+}
+
+impl RunsComponent {
+    pub fn new() -> Self {
+        let runs = {
+            #[cfg(feature = "no_save")]
+            {
+                Vec::new()
+            }
+
+            #[cfg(not(feature = "no_save"))]
+            {
+                Storage::load_runs().unwrap_or_default()
+            }
+        };
+        let (in_progress, history): (Vec<Run>, Vec<Run>) =
+            runs.into_iter().partition(|r| r.is_in_progress());
+
+        Self {
+            current: in_progress.into_iter().next(),
+            history,
+            pending_attributions: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, message: RunsMessage) {
+        match message {
+            RunsMessage::StartRun => {
+                self.start_run();
+            }
+            RunsMessage::EndRun(outcome) => {
+                if let Some(mut run) = self.current.take() {
+                    self.pending_attributions.clear();
+                    run.finish(outcome);
+                    Self::save_run(&run);
+                    self.history.insert(0, run);
+                }
+            }
+            RunsMessage::NightlordSelected(nightlord) => {
+                if let Some(run) = self.current.as_mut() {
+                    run.set_nightlord(Some(nightlord));
+                    Self::save_run(run);
+                }
+            }
+            RunsMessage::OcrDeath(death_id) => {
+                // Une mort hors expédition en démarre une nouvelle
+                if self.current.is_none() {
+                    self.start_run();
+                }
+                if let Some(run) = self.current.as_mut() {
+                    self.pending_attributions
+                        .push((death_id, run.get_deaths().len()));
+                    run.add_death(Vec::new());
+                    Self::save_run(run);
+                }
+            }
+            RunsMessage::OcrBosses(death_id, bosses, boss_health) => {
+                let Some(pos) = self
+                    .pending_attributions
+                    .iter()
+                    .position(|(id, _)| *id == death_id)
+                else {
+                    return;
+                };
+                let (_, index) = self.pending_attributions.remove(pos);
+                if let Some(run) = self.current.as_mut() {
+                    run.attribute_death(
+                        index,
+                        bosses
+                            .into_iter()
+                            .filter(|b| !b.trim().is_empty())
+                            .map(|b| b.trim().to_string())
+                            .collect(),
+                        boss_health,
+                    );
+                    Self::save_run(run);
+                }
+            }
+            RunsMessage::CancelAttributions => {
+                self.pending_attributions.clear();
+            }
+        }
+    }
+
+    fn start_run(&mut self) {
+        let run = Run::new();
+        Self::save_run(&run);
+        self.current = Some(run);
+    }
+
+    fn save_run(run: &Run) {
+        if let Err(e) = Storage::save_run(run) {
+            error!("❌ Erreur sauvegarde expédition: {}", e);
+        }
+    }
+
+    pub fn view<'a>(&'a self, i18n: &'a I18n) -> Element<'a, RunsMessage> {
+        let summary = summarize_runs(&self.history);
+        let summary_rows = summary.into_iter().map(|s| {
+            let deaths_per_run = s.deaths_per_run();
+            row![
+                text(s.nightlord).size(18).width(Length::Fill),
+                text(format!("{} {}", s.runs, i18n.runs(RunsKey::Runs))).width(120),
+                text(format!("{} {}", s.victories, i18n.runs(RunsKey::Victories))).width(120),
+                text(format!(
+                    "{:.1} {}",
+                    deaths_per_run,
+                    i18n.runs(RunsKey::DeathsPerRun)
+                ))
+                .width(160),
+            ]
+            .spacing(10)
+            .into()
+        });
+
+        let recent_rows = self.history.iter().take(RECENT_RUNS).map(|run| {
+            row![
+                text(
+                    run.get_nightlord()
+                        .cloned()
+                        .unwrap_or_else(|| i18n.runs(RunsKey::UnknownNightlord).to_string())
+                )
+                .width(Length::Fill),
+                text(Self::outcome_label(run.get_outcome(), i18n)).width(120),
+                text(format!(
+                    "{} {}",
+                    run.get_deaths().len(),
+                    i18n.runs(RunsKey::Deaths)
+                ))
+                .width(120),
+                text(format_timestamp(Duration::from_secs(run.duration_secs()))).width(100),
+            ]
+            .spacing(10)
+            .into()
+        });
+
+        let content = column![
+            self.view_current_run(i18n),
+            text(i18n.runs(RunsKey::Summary)).size(22),
+            column(summary_rows.collect::<Vec<_>>()).spacing(5),
+            text(i18n.runs(RunsKey::RecentRuns)).size(22),
+            column(recent_rows.collect::<Vec<_>>()).spacing(5),
+        ]
+        .spacing(15)
+        .width(Length::Fill);
+
+        scrollable(content).height(Length::Fill).into()
+    }
+
+    fn view_current_run<'a>(&'a self, i18n: &'a I18n) -> Element<'a, RunsMessage> {
+        let Some(run) = &self.current else {
+            return container(
+                row![
+                    text(i18n.runs(RunsKey::NoRun)).size(20).width(Length::Fill),
+                    button(i18n.runs(RunsKey::StartRun)).on_press(RunsMessage::StartRun),
+                ]
+                .spacing(20)
+                .align_y(Alignment::Center),
+            )
+            .padding(20)
+            .width(Length::Fill)
+            .style(crate::style::style::container_inactive)
+            .into();
+        };
+
+        let selected_nightlord = run
+            .get_nightlord()
+            .and_then(|n| NIGHTLORDS.iter().copied().find(|lord| *lord == n.as_str()));
+
+        let night_bosses = if run.get_night_bosses().is_empty() {
+            "-".to_string()
+        } else {
+            run.get_night_bosses().join(" - ")
+        };
+
+        let run_column = column![
+            row![
+                text(i18n.runs(RunsKey::Title))
+                    .size(22)
+                    .width(Length::Fill)
+                    .color(Color::from_rgb(0.6, 0.7, 1.0)),
+                text(format_timestamp(Duration::from_secs(run.duration_secs()))).size(18),
+            ]
+            .spacing(20),
+            row![
+                text(i18n.runs(RunsKey::Nightlord)).width(150),
+                pick_list(NIGHTLORDS, selected_nightlord, |nightlord: &str| {
+                    RunsMessage::NightlordSelected(nightlord.to_string())
+                })
+                .placeholder(i18n.runs(RunsKey::UnknownNightlord)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            row![
+                text(i18n.runs(RunsKey::Deaths)).width(150),
+                text(run.get_deaths().len().to_string()).size(20),
+            ]
+            .spacing(10),
+            row![
+                text(i18n.runs(RunsKey::NightBosses)).width(150),
+                text(night_bosses).width(Length::Fill),
+            ]
+            .spacing(10),
+            row![
+                button(i18n.runs(RunsKey::Victory))
+                    .on_press(RunsMessage::EndRun(RunOutcome::Victory)),
+                button(i18n.runs(RunsKey::Defeat))
+                    .on_press(RunsMessage::EndRun(RunOutcome::Defeat)),
+                button(i18n.runs(RunsKey::Abandon))
+                    .on_press(RunsMessage::EndRun(RunOutcome::Abandoned)),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10);
+
+        container(run_column)
+            .padding(20)
+            .width(Length::Fill)
+            .style(crate::style::style::container_active)
+            .into()
+    }
+
+    fn outcome_label(outcome: RunOutcome, i18n: &I18n) -> &'static str {
+        match outcome {
+            RunOutcome::InProgress => i18n.runs(RunsKey::Title),
+            RunOutcome::Victory => i18n.runs(RunsKey::Victory),
+            RunOutcome::Defeat => i18n.runs(RunsKey::Defeat),
+            RunOutcome::Abandoned => i18n.runs(RunsKey::Abandon),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::i18n::translations::{GeneralKey, I18n, LogsKey};
+use crate::screens::settings_screen::SettingsScreen;
+use crate::structs::app::Screen;
+use crate::utils::logging::{log_dir, recent_lines};
+use iced::widget::{button, column, row, scrollable, text, toggler};
+use iced::{Alignment, Color, Element, Font, Length, Subscription, Task, time::Duration};
+use std::path::PathBuf;
+
+/// Rafraîchissement de la page tant que le suivi est actif
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone)]
+pub enum LogMessage {
+    Refresh,
+    Follow(bool),
+    Back,
+    ChangeView(Screen),
+}
+
+#[derive(Debug, Clone)]
+pub struct LogScreen {
+    lines: Vec<String>,
+    follow: bool,
+    /// Dossier des fichiers journaux (ou l'erreur pour le trouver)
+    folder: Result<PathBuf, String>,
+}
+
+impl LogScreen {
+    pub fn new() -> Self {
+        Self {
+            lines: recent_lines(),
+            follow: true,
+            folder: log_dir(),
+        }
+    }
+
+    pub fn update(&mut self, message: LogMessage) -> Task<LogMessage> {
+        match message {
+            LogMessage::Refresh => {
+                self.lines = recent_lines();
+                Task::none()
+            }
+            LogMessage::Follow(follow) => {
+                self.follow = follow;
+                if follow {
+                    self.lines = recent_lines();
+                }
+                Task::none()
+            }
+            LogMessage::Back => Task::done(LogMessage::ChangeView(Screen::SettingsScreen(
+                SettingsScreen::new(),
+            ))),
+            LogMessage::ChangeView(_) => Task::none(),
+        }
+    }
+
+    pub fn view(&self, i18n: &I18n) -> Element<'_, LogMessage> {
+        let folder = match &self.folder {
+            Ok(dir) => format!("{} {}", i18n.logs(LogsKey::Folder), dir.display()),
+            Err(e) => e.clone(),
+        };
+
+        let lines: Element<'_, LogMessage> = if self.lines.is_empty() {
+            text(i18n.logs(LogsKey::Empty)).size(14).into()
+        } else {
+            column(
+                self.lines
+                    .iter()
+                    .map(|line| Self::view_line(line))
+                    .collect::<Vec<_>>(),
+            )
+            .spacing(2)
+            .width(Length::Fill)
+            .into()
+        };
+
+        column![
+            row![
+                text(i18n.logs(LogsKey::Title)).size(28).width(Length::Fill),
+                text(i18n.logs(LogsKey::Follow)).size(14),
+                toggler(self.follow).on_toggle(LogMessage::Follow),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text(folder).size(13).color(Color::from_rgb(0.6, 0.6, 0.6)),
+            scrollable(lines)
+                .anchor_bottom()
+                .height(Length::Fill)
+                .width(Length::Fill),
+            button(i18n.general(GeneralKey::Back)).on_press(LogMessage::Back),
+        ]
+        .spacing(15)
+        .padding(25)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    // Une ligne du journal, colorée selon son niveau
+    fn view_line(line: &str) -> Element<'_, LogMessage> {
+        let color = if line.contains(" ERROR ") {
+            Color::from_rgb(1.0, 0.35, 0.35)
+        } else if line.contains(" WARN ") {
+            Color::from_rgb(1.0, 0.7, 0.3)
+        } else if line.contains(" DEBUG ") || line.contains(" TRACE ") {
+            Color::from_rgb(0.6, 0.6, 0.6)
+        } else {
+            Color::from_rgb(0.9, 0.9, 0.9)
+        };
+        text(line)
+            .size(12)
+            .font(Font::MONOSPACE)
+            .color(color)
+            .into()
+    }
+
+    pub fn subscription(&self) -> Subscription<LogMessage> {
+        if self.follow {
+            iced::time::every(REFRESH_INTERVAL).map(|_| LogMessage::Refresh)
+        } else {
+            Subscription::none()
+        }
+    }
+}
@@ -2,8 +2,11 @@ use crate::i18n::translations::{GeneralKey, I18n};
 use crate::screens::add_recorder_screen::AddRecorderScreen;
 use crate::screens::components::list::{ListComponent, ListMessage};
 use crate::screens::components::ocr::{OcrComponent, OcrMessage};
+use crate::screens::components::runs::{RunsComponent, RunsMessage};
 use crate::screens::settings_screen::SettingsScreen;
+use crate::screens::vod_screen::VodScreen;
 use crate::structs::app::Screen;
+use crate::structs::settings::settings::Settings;
 use iced::widget::{button, column, row};
 use iced::{Element, Length, Subscription, Task};
 // -------------------------------------------------------
@@ -13,6 +16,7 @@ use iced::{Element, Length, Subscription, Task};
 #[derive(Debug, Clone)]
 pub enum MainScreenMessage {
     List(ListMessage),
+    Runs(RunsMessage),
     Ocr(OcrMessage),
     ChangeView(Screen),
 }
@@ -23,14 +27,19 @@ pub enum MainScreenMessage {
 #[derive(Clone, Debug, Default)]
 pub struct MainScreen {
     list: ListComponent,
+    runs: RunsComponent,
     ocr: OcrComponent,
+    /// Jeu en expéditions (Nightreign) : les morts vont dans l'expédition en cours
+    run_based: bool,
 }
 
 impl MainScreen {
     pub fn new() -> Self {
         Self {
             list: ListComponent::new(),
+            runs: RunsComponent::new(),
             ocr: OcrComponent::new(),
+            run_based: Settings::load().get_game().is_run_based(),
         }
     }
 
@@ -40,9 +49,50 @@ impl MainScreen {
             MainScreenMessage::List(message) => {
                 self.list.update(message).map(MainScreenMessage::List)
             }
+            MainScreenMessage::Runs(message) => {
+                self.runs.update(message);
+                Task::none()
+            }
             MainScreenMessage::Ocr(message) => match message {
-                OcrMessage::BossesFoundOCR(bosses) => {
-                    Task::done(MainScreenMessage::List(ListMessage::OcrDeath(bosses)))
+                // Compter la mort dès sa confirmation, sans attendre la lecture des boss
+                OcrMessage::DeathDetected(death_id) => {
+                    let _ = self.ocr.update(OcrMessage::DeathDetected(death_id));
+                    if self.run_based {
+                        Task::done(MainScreenMessage::Runs(RunsMessage::OcrDeath(death_id)))
+                    } else {
+                        Task::done(MainScreenMessage::List(ListMessage::OcrDeath(death_id)))
+                    }
+                }
+                OcrMessage::BossesFoundOCR(death_id, bosses, health) => {
+                    let _ = self.ocr.update(OcrMessage::BossesFoundOCR(
+                        death_id,
+                        bosses.clone(),
+                        health.clone(),
+                    ));
+                    if self.run_based {
+                        Task::done(MainScreenMessage::Runs(RunsMessage::OcrBosses(
+                            death_id, bosses, health,
+                        )))
+                    } else {
+                        Task::done(MainScreenMessage::List(ListMessage::OcrBosses(
+                            death_id, bosses, health,
+                        )))
+                    }
+                }
+                // OCR arrêté ou worker relancé : les lectures de boss en cours n'arriveront plus
+                OcrMessage::ActivateOCR(false) | OcrMessage::WorkerStarted => {
+                    let task = self.ocr.update(message).map(MainScreenMessage::Ocr);
+                    let _ = self.list.update(ListMessage::CancelAttributions);
+                    self.runs.update(RunsMessage::CancelAttributions);
+                    task
+                }
+                OcrMessage::GameDetected(game) => {
+                    let task = self
+                        .ocr
+                        .update(OcrMessage::GameDetected(game))
+                        .map(MainScreenMessage::Ocr);
+                    self.run_based = self.ocr.get_game().is_run_based();
+                    task
                 }
                 _ => self.ocr.update(message).map(MainScreenMessage::Ocr),
             },
@@ -50,9 +100,15 @@ impl MainScreen {
     }
 
     pub fn view<'a>(&'a self, i18n: &'a I18n) -> Element<'a, MainScreenMessage> {
+        let counters = if self.run_based {
+            self.runs.view(i18n).map(MainScreenMessage::Runs)
+        } else {
+            self.list.view(i18n).map(MainScreenMessage::List)
+        };
+
         column![
             self.ocr.view(i18n).map(MainScreenMessage::Ocr),
-            self.list.view(i18n).map(MainScreenMessage::List),
+            counters,
             row![
                 button(i18n.general(GeneralKey::AddRecorder)).on_press(
                     MainScreenMessage::ChangeView(crate::structs::app::Screen::AddRecorderScreen(
@@ -61,7 +117,12 @@ impl MainScreen {
                 ),
                 button(i18n.general(GeneralKey::Settings)).on_press(MainScreenMessage::ChangeView(
                     crate::structs::app::Screen::SettingsScreen(SettingsScreen::new())
-                ))
+                )),
+                button(i18n.general(GeneralKey::AnalyzeVod)).on_press(
+                    MainScreenMessage::ChangeView(crate::structs::app::Screen::VodScreen(
+                        VodScreen::new()
+                    ))
+                )
             ]
             .padding(10)
             .spacing(10)
@@ -1,4 +1,7 @@
 pub mod add_recorder_screen;
 pub mod components;
+pub mod log_screen;
 pub mod main_screen;
 pub mod settings_screen;
+pub mod vod_screen;
+pub mod zone_editor_screen;
@@ -4,30 +4,49 @@ use crate::{
         language::ALL_LANGUAGES,
         translations::{GeneralKey, I18n, SettingsKey},
     },
-    screens::main_screen::MainScreen,
+    screens::{
+        log_screen::LogScreen, main_screen::MainScreen, zone_editor_screen::ZoneEditorScreen,
+    },
     structs::{
         app::Screen,
         settings::{
+            capture_source::{ALL_CAPTURE_MODES, CaptureMode},
             game::{ALL_GAMES, Game},
-            screen::{ScreenInfo, get_screens_vec},
+            log_level::{ALL_LOG_LEVELS, LogLevel},
+            screen::{ScreenInfo, get_screens_vec, get_windows_vec},
             settings::Settings,
         },
     },
+    utils::{logging, scan_budget::CPU_BUDGET_RANGE},
 };
 
 use iced::{Alignment, Element, Length, Task};
 use iced::{
     Subscription,
-    widget::{PickList, button, column, container, pick_list, row, text, text_input},
+    widget::{
+        PickList, button, column, container, pick_list, row, slider, text, text_input, toggler,
+    },
 };
 
 #[derive(Debug, Clone)]
 pub enum SettingsScreenMessage {
     SaveSettings,
     GameSelected(Game),
+    AutoDetectGame(bool),
+    AutoStartOcr(bool),
+    CpuBudget(u8),
+    LogLevelSelected(LogLevel),
+    ShowLogs,
     LanguageSelected(Language),
     ScreenSelected(ScreenInfo),
+    CaptureModeSelected(CaptureMode),
+    WindowSelected(String),
+    WindowName(String),
+    SourcePath(String),
+    StdinFrameSize(String),
     DeathText(String),
+    ToggleDeathTextPreset(&'static str),
+    EditZones,
     ChangeLanguageI18n(Language),
     ChangeView(Screen),
 }
@@ -36,15 +55,18 @@ pub enum SettingsScreenMessage {
 pub struct SettingsScreen {
     settings: Settings,
     screens_list: Vec<ScreenInfo>,
+    windows_list: Vec<String>,
 }
 
 impl SettingsScreen {
     pub fn new() -> Self {
         let screens_list = get_screens_vec().unwrap_or_default();
+        let windows_list = get_windows_vec().unwrap_or_default();
         let settings = Settings::load();
         Self {
             settings,
             screens_list,
+            windows_list,
         }
     }
 
@@ -58,9 +80,33 @@ impl SettingsScreen {
             }
             SettingsScreenMessage::ChangeLanguageI18n(_) => Task::none(),
             SettingsScreenMessage::GameSelected(game) => {
-                self.settings.set_game(game);
+                self.settings.switch_game(game);
+                Task::none()
+            }
+            SettingsScreenMessage::AutoDetectGame(enabled) => {
+                self.settings.set_auto_detect_game(enabled);
+                Task::none()
+            }
+            SettingsScreenMessage::AutoStartOcr(enabled) => {
+                self.settings.set_auto_start_ocr(enabled);
                 Task::none()
             }
+            SettingsScreenMessage::CpuBudget(percent) => {
+                self.settings.set_cpu_budget(percent);
+                Task::none()
+            }
+            SettingsScreenMessage::LogLevelSelected(level) => {
+                // Appliqué tout de suite, sans attendre l'enregistrement
+                self.settings.set_log_level(level);
+                logging::set_level(level);
+                Task::none()
+            }
+            SettingsScreenMessage::ShowLogs => {
+                self.settings.save();
+                Task::done(SettingsScreenMessage::ChangeView(Screen::LogScreen(
+                    LogScreen::new(),
+                )))
+            }
             SettingsScreenMessage::LanguageSelected(language) => {
                 self.set_language(language.clone());
                 Task::done(SettingsScreenMessage::ChangeLanguageI18n(language))
@@ -69,10 +115,41 @@ impl SettingsScreen {
                 self.settings.set_screen(screen.index);
                 Task::none()
             }
+            SettingsScreenMessage::CaptureModeSelected(mode) => {
+                self.settings.set_capture_mode(mode);
+                if mode == CaptureMode::Window {
+                    self.windows_list = get_windows_vec().unwrap_or_default();
+                }
+                Task::none()
+            }
+            SettingsScreenMessage::WindowSelected(window_name)
+            | SettingsScreenMessage::WindowName(window_name) => {
+                self.settings.set_window_name(window_name);
+                Task::none()
+            }
+            SettingsScreenMessage::SourcePath(path) => {
+                self.settings.set_source_path(path);
+                Task::none()
+            }
+            SettingsScreenMessage::StdinFrameSize(size) => {
+                self.settings.set_stdin_frame_size(size);
+                Task::none()
+            }
             SettingsScreenMessage::DeathText(text) => {
                 self.settings.set_death_text(text);
                 Task::none()
             }
+            SettingsScreenMessage::ToggleDeathTextPreset(text) => {
+                self.settings.toggle_death_text_preset(text);
+                Task::none()
+            }
+            SettingsScreenMessage::EditZones => {
+                // Le jeu choisi ici doit être celui de l'éditeur
+                self.settings.save();
+                Task::done(SettingsScreenMessage::ChangeView(Screen::ZoneEditorScreen(
+                    ZoneEditorScreen::new(),
+                )))
+            }
             SettingsScreenMessage::ChangeView(_) => Task::none(),
         }
     }
@@ -95,6 +172,32 @@ impl SettingsScreen {
             ]
             .align_y(Alignment::Center)
             .spacing(spacing_item),
+            row![
+                text(i18n.settings(SettingsKey::AutoDetectGame)).width(Length::Fill),
+                toggler(self.settings.get_auto_detect_game())
+                    .on_toggle(SettingsScreenMessage::AutoDetectGame),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item),
+            row![
+                text(i18n.settings(SettingsKey::AutoStartOcr)).width(Length::Fill),
+                toggler(self.settings.get_auto_start_ocr())
+                    .on_toggle(SettingsScreenMessage::AutoStartOcr),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item),
+            row![
+                text(i18n.settings(SettingsKey::CpuBudget)).width(label_width),
+                slider(
+                    CPU_BUDGET_RANGE,
+                    self.settings.get_cpu_budget(),
+                    SettingsScreenMessage::CpuBudget
+                )
+                .width(Length::Fill),
+                text(format!("{} %", self.settings.get_cpu_budget())).width(50),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item),
             row![
                 text(i18n.settings(SettingsKey::Language)).width(label_width),
                 pick_list(
@@ -107,19 +210,17 @@ impl SettingsScreen {
             .align_y(Alignment::Center)
             .spacing(spacing_item),
             row![
-                text(format!("{}", i18n.settings(SettingsKey::Screen))).width(label_width),
-                PickList::new(
-                    self.screens_list.as_slice(),
-                    self.screens_list
-                        .iter()
-                        .find(|s| s.index == self.settings.get_screen())
-                        .cloned(),
-                    SettingsScreenMessage::ScreenSelected,
+                text(i18n.settings(SettingsKey::CaptureMode)).width(label_width),
+                pick_list(
+                    ALL_CAPTURE_MODES,
+                    Some(self.settings.get_capture_mode()),
+                    SettingsScreenMessage::CaptureModeSelected,
                 )
                 .width(Length::Fill)
             ]
             .align_y(Alignment::Center)
             .spacing(spacing_item),
+            self.view_capture_source(i18n, label_width, spacing_item),
             row![
                 text(i18n.settings(SettingsKey::DeathText)).width(label_width),
                 text_input(
@@ -131,6 +232,28 @@ impl SettingsScreen {
             ]
             .align_y(Alignment::Center)
             .spacing(spacing_item),
+            row![
+                text(i18n.settings(SettingsKey::DeathTextPresets)).width(label_width),
+                self.view_death_text_presets(i18n)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item),
+            row![
+                text(i18n.settings(SettingsKey::LogLevel)).width(label_width),
+                pick_list(
+                    ALL_LOG_LEVELS,
+                    Some(self.settings.get_log_level()),
+                    SettingsScreenMessage::LogLevelSelected,
+                )
+                .width(Length::Fill),
+                button(i18n.settings(SettingsKey::ShowLogs))
+                    .on_press(SettingsScreenMessage::ShowLogs),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item),
+            button(i18n.settings(SettingsKey::EditZones))
+                .on_press(SettingsScreenMessage::EditZones)
+                .width(Length::Fill),
             button(i18n.general(GeneralKey::Save))
                 .on_press(SettingsScreenMessage::SaveSettings)
                 .width(Length::Fill)
@@ -141,6 +264,100 @@ impl SettingsScreen {
         container(form).center_x(Length::Fill).padding(30).into()
     }
 
+    // Un bouton par langue du jeu, en surbrillance quand le texte est accepté
+    fn view_death_text_presets(&self, i18n: &I18n) -> Element<'_, SettingsScreenMessage> {
+        let presets = self.settings.get_game().death_text_presets();
+        if presets.is_empty() {
+            return text(i18n.settings(SettingsKey::DeathGlyphOnly))
+                .size(14)
+                .into();
+        }
+
+        let buttons = presets.iter().map(|preset| {
+            let selected = self.settings.is_death_text_preset_selected(preset.text);
+            button(text(format!("{} · {}", preset.locale, preset.text)).size(14))
+                .style(if selected {
+                    button::primary
+                } else {
+                    button::secondary
+                })
+                .on_press(SettingsScreenMessage::ToggleDeathTextPreset(preset.text))
+                .into()
+        });
+
+        row(buttons.collect::<Vec<_>>()).spacing(5).wrap().into()
+    }
+
+    fn view_capture_source(
+        &self,
+        i18n: &I18n,
+        label_width: u32,
+        spacing_item: u32,
+    ) -> Element<'_, SettingsScreenMessage> {
+        match self.settings.get_capture_mode() {
+            CaptureMode::Monitor => row![
+                text(i18n.settings(SettingsKey::Screen)).width(label_width),
+                PickList::new(
+                    self.screens_list.as_slice(),
+                    self.screens_list
+                        .iter()
+                        .find(|s| s.index == self.settings.get_screen())
+                        .cloned(),
+                    SettingsScreenMessage::ScreenSelected,
+                )
+                .width(Length::Fill)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item)
+            .into(),
+            CaptureMode::Window => row![
+                text(i18n.settings(SettingsKey::Window)).width(label_width),
+                column![
+                    text_input(
+                        i18n.settings(SettingsKey::WindowInput),
+                        self.settings.get_window_name()
+                    )
+                    .on_input(SettingsScreenMessage::WindowName)
+                    .width(Length::Fill),
+                    pick_list(
+                        self.windows_list.as_slice(),
+                        self.windows_list
+                            .iter()
+                            .find(|w| *w == self.settings.get_window_name())
+                            .cloned(),
+                        SettingsScreenMessage::WindowSelected,
+                    )
+                    .width(Length::Fill)
+                ]
+                .spacing(5)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item)
+            .into(),
+            CaptureMode::ImageFolder | CaptureMode::VideoFile => row![
+                text(i18n.settings(SettingsKey::SourcePath)).width(label_width),
+                text_input(
+                    i18n.settings(SettingsKey::SourcePathInput),
+                    self.settings.get_source_path()
+                )
+                .on_input(SettingsScreenMessage::SourcePath)
+                .width(Length::Fill)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item)
+            .into(),
+            CaptureMode::Stdin => row![
+                text(i18n.settings(SettingsKey::FrameSize)).width(label_width),
+                text_input("1920x1080", self.settings.get_stdin_frame_size())
+                    .on_input(SettingsScreenMessage::StdinFrameSize)
+                    .width(Length::Fill)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(spacing_item)
+            .into(),
+        }
+    }
+
     pub fn subscription(&self) -> Subscription<SettingsScreenMessage> {
         Subscription::none()
     }
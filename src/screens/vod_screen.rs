@@ -0,0 +1,236 @@
+use crate::i18n::translations::{GeneralKey, I18n, VodKey};
+use crate::screens::components::list::ListComponent;
+use crate::screens::main_screen::MainScreen;
+use crate::structs::app::Screen;
+use crate::structs::settings::settings::Settings;
+use crate::utils::app_worker::vod_subscription;
+use crate::utils::export::export_all;
+use crate::vod::vod::{VodDeath, VodEvent, format_timestamp};
+use iced::widget::{button, column, container, image, row, scrollable, text, text_input};
+use iced::{Alignment, Color, Element, Length, Subscription, Task, time::Duration};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub enum VodMessage {
+    PathChanged(String),
+    StartAnalysis,
+    CancelAnalysis,
+    Event(VodEvent),
+    AnalysisFinished(Result<usize, String>),
+    ImportDeaths,
+    ExportDeaths,
+    Back,
+    ChangeView(Screen),
+}
+
+#[derive(Debug, Clone)]
+pub struct VodScreen {
+    settings: Settings,
+    path: String,
+    analyzing: bool,
+    position: Duration,
+    deaths: Vec<VodDeath>,
+    error: Option<String>,
+    imported: bool,
+    exported: Vec<PathBuf>,
+}
+
+impl VodScreen {
+    pub fn new() -> Self {
+        Self {
+            settings: Settings::load(),
+            path: String::new(),
+            analyzing: false,
+            position: Duration::ZERO,
+            deaths: Vec::new(),
+            error: None,
+            imported: false,
+            exported: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, message: VodMessage) -> Task<VodMessage> {
+        match message {
+            VodMessage::PathChanged(path) => {
+                self.path = path;
+                Task::none()
+            }
+            VodMessage::StartAnalysis => {
+                if !PathBuf::from(self.path.trim()).is_file() {
+                    self.error = Some(format!("Fichier introuvable : {}", self.path));
+                    return Task::none();
+                }
+                self.deaths.clear();
+                self.position = Duration::ZERO;
+                self.error = None;
+                self.imported = false;
+                self.exported.clear();
+                self.analyzing = true;
+                Task::none()
+            }
+            VodMessage::CancelAnalysis => {
+                self.analyzing = false;
+                Task::none()
+            }
+            VodMessage::Event(event) => {
+                match event {
+                    VodEvent::Progress(position) => self.position = position,
+                    VodEvent::DeathFound(death) => self.deaths.push(death),
+                }
+                Task::none()
+            }
+            VodMessage::AnalysisFinished(result) => {
+                self.analyzing = false;
+                if let Err(e) = result {
+                    self.error = Some(e);
+                }
+                Task::none()
+            }
+            VodMessage::ImportDeaths => {
+                let mut list = ListComponent::new();
+                list.import_deaths(self.deaths.iter().map(|d| d.bosses.clone()).collect());
+                list.save();
+                self.imported = true;
+                Task::none()
+            }
+            VodMessage::ExportDeaths => {
+                let markers: Vec<_> = self.deaths.iter().map(|d| d.to_marker()).collect();
+                match export_all(&markers, &PathBuf::from(self.path.trim())) {
+                    Ok(files) => self.exported = files,
+                    Err(e) => self.error = Some(e),
+                }
+                Task::none()
+            }
+            VodMessage::Back => Task::done(VodMessage::ChangeView(Screen::MainScreen(
+                MainScreen::new(),
+            ))),
+            VodMessage::ChangeView(_) => Task::none(),
+        }
+    }
+
+    pub fn view(&self, i18n: &I18n) -> Element<'_, VodMessage> {
+        let path_row = if self.analyzing {
+            row![
+                text_input(i18n.vod(VodKey::PathInput), &self.path).width(Length::Fill),
+                button(i18n.vod(VodKey::Cancel)).on_press(VodMessage::CancelAnalysis)
+            ]
+        } else {
+            row![
+                text_input(i18n.vod(VodKey::PathInput), &self.path)
+                    .on_input(VodMessage::PathChanged)
+                    .on_submit(VodMessage::StartAnalysis)
+                    .width(Length::Fill),
+                button(i18n.vod(VodKey::Analyze)).on_press(VodMessage::StartAnalysis)
+            ]
+        }
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let status = if self.analyzing {
+            format!(
+                "{} {}",
+                i18n.vod(VodKey::Analyzing),
+                format_timestamp(self.position)
+            )
+        } else {
+            format!("{} {}", self.deaths.len(), i18n.vod(VodKey::DeathsFound))
+        };
+
+        let error = if let Some(error) = &self.error {
+            text(error).size(14).color(Color::from_rgb(0.8, 0.0, 0.0))
+        } else {
+            text("")
+        };
+
+        let timeline = column(
+            self.deaths
+                .iter()
+                .enumerate()
+                .map(|(index, death)| Self::view_death(index, death, i18n))
+                .collect::<Vec<_>>(),
+        )
+        .spacing(10)
+        .width(Length::Fill);
+
+        let import_button = if !self.analyzing && !self.deaths.is_empty() && !self.imported {
+            button(i18n.vod(VodKey::Import)).on_press(VodMessage::ImportDeaths)
+        } else {
+            button(i18n.vod(VodKey::Import))
+        };
+        let export_button = if !self.analyzing && !self.deaths.is_empty() {
+            button(i18n.vod(VodKey::Export)).on_press(VodMessage::ExportDeaths)
+        } else {
+            button(i18n.vod(VodKey::Export))
+        };
+        let exported = column(
+            self.exported
+                .iter()
+                .map(|path| {
+                    text(format!("{} {}", i18n.vod(VodKey::Exported), path.display()))
+                        .size(14)
+                        .into()
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        column![
+            text(i18n.vod(VodKey::Title)).size(28),
+            path_row,
+            text(status).size(16),
+            error,
+            scrollable(timeline).height(Length::Fill),
+            exported,
+            row![
+                button(i18n.general(GeneralKey::Back)).on_press(VodMessage::Back),
+                import_button,
+                export_button,
+            ]
+            .spacing(10)
+        ]
+        .spacing(15)
+        .padding(25)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_death<'a>(index: usize, death: &'a VodDeath, i18n: &I18n) -> Element<'a, VodMessage> {
+        let bosses = if death.bosses.is_empty() {
+            i18n.vod(VodKey::NoBoss).to_string()
+        } else {
+            death.bosses.join(" - ")
+        };
+
+        let thumbnail: Element<'a, VodMessage> = match &death.thumbnail {
+            Some(path) => image(path.clone()).width(160).into(),
+            None => text("").into(),
+        };
+
+        container(
+            row![
+                text(format!("#{}", index + 1)).size(18).width(50),
+                text(format_timestamp(death.timestamp)).size(18).width(100),
+                text(bosses).size(18).width(Length::Fill),
+                thumbnail,
+            ]
+            .spacing(20)
+            .align_y(Alignment::Center),
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(crate::style::style::container_global_deaths)
+        .into()
+    }
+
+    pub fn subscription(&self) -> Subscription<VodMessage> {
+        if self.analyzing {
+            vod_subscription(
+                PathBuf::from(self.path.trim()),
+                self.settings.get_game_config(),
+                self.settings.get_death_texts(),
+            )
+        } else {
+            Subscription::none()
+        }
+    }
+}
@@ -0,0 +1,742 @@
+use crate::i18n::translations::{GeneralKey, I18n, ZoneEditorKey};
+use crate::ocr::ocr::preview_zone;
+use crate::screens::settings_screen::SettingsScreen;
+use crate::structs::app::Screen;
+use crate::structs::settings::capture_source::CaptureMode;
+use crate::structs::settings::crop_position::{ContentArea, CropPosition, UI_ASPECT_16_9};
+use crate::structs::settings::crop_preset::{
+    CropPreset, export_presets, import_presets, suggest_preset,
+};
+use crate::structs::settings::game::GameConfig;
+use crate::structs::settings::screen::get_screens_vec;
+use crate::structs::settings::settings::Settings;
+use crate::utils::screen_capture::{capture_screen, capture_window};
+use ::image::DynamicImage;
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Event, Frame, Geometry, Path, Stroke};
+use iced::widget::{
+    button, column, container, image, pick_list, row, scrollable, stack, text, text_input,
+};
+use iced::{
+    Alignment, Color, ContentFit, Element, Length, Point, Rectangle, Renderer, Size, Subscription,
+    Task, Theme,
+};
+
+/// Largeur d'affichage de la capture dans l'éditeur (la hauteur suit le ratio de l'image)
+const EDITOR_WIDTH: f32 = 800.0;
+
+const DEATH_ZONE_COLOR: Color = Color::from_rgb(1.0, 0.25, 0.25);
+const BOSS_ZONE_COLOR: Color = Color::from_rgb(0.4, 0.7, 1.0);
+const SELECTED_ZONE_COLOR: Color = Color::from_rgb(1.0, 0.85, 0.2);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZoneTarget {
+    Death,
+    Boss(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum ZoneEditorMessage {
+    Capture,
+    PathChanged(String),
+    LoadImage,
+    ScreenshotLoaded(Result<DynamicImage, String>),
+    ZoneSelected(ZoneTarget),
+    ZoneDrawn(CropPosition),
+    AddBossZone,
+    RemoveBossZone,
+    PreviewReady(Result<(DynamicImage, String), String>),
+    ResetDefaults,
+    PresetSelected(CropPreset),
+    ApplyPreset(CropPreset),
+    PresetNameChanged(String),
+    SavePreset,
+    PresetPathChanged(String),
+    ImportPresets,
+    ExportPresets,
+    Save,
+    Back,
+    ChangeView(Screen),
+}
+
+#[derive(Debug, Clone)]
+pub struct ZoneEditorScreen {
+    settings: Settings,
+    game_config: GameConfig,
+    path: String,
+    screenshot: Option<DynamicImage>,
+    screenshot_handle: Option<image::Handle>,
+    /// Zone utile de la capture (sans bandes noires)
+    content_area: Option<ContentArea>,
+    selected: ZoneTarget,
+    preview: Option<image::Handle>,
+    ocr_text: Option<String>,
+    /// Presets du jeu actuel et preset suggéré pour l'écran choisi
+    presets: Vec<CropPreset>,
+    selected_preset: Option<CropPreset>,
+    suggested_preset: Option<CropPreset>,
+    /// Résolution de l'écran choisi dans les paramètres
+    monitor_size: Option<(u32, u32)>,
+    preset_name: String,
+    preset_path: String,
+    /// Message de succès de l'import / export
+    info: Option<(ZoneEditorKey, String)>,
+    error: Option<String>,
+}
+
+impl ZoneEditorScreen {
+    pub fn new() -> Self {
+        let settings = Settings::load();
+        let game_config = settings.get_game_config();
+        let monitor_size = get_screens_vec()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|s| s.index == settings.get_screen() && s.width > 0 && s.height > 0)
+            .map(|s| (s.width, s.height));
+        let mut screen = Self {
+            settings,
+            game_config,
+            path: String::new(),
+            screenshot: None,
+            screenshot_handle: None,
+            content_area: None,
+            selected: ZoneTarget::Death,
+            preview: None,
+            ocr_text: None,
+            presets: Vec::new(),
+            selected_preset: None,
+            suggested_preset: None,
+            monitor_size,
+            preset_name: String::new(),
+            preset_path: String::new(),
+            info: None,
+            error: None,
+        };
+        screen.refresh_presets();
+        screen
+    }
+
+    fn refresh_presets(&mut self) {
+        let game = self.settings.get_game();
+        self.presets = self
+            .settings
+            .get_crop_presets()
+            .iter()
+            .filter(|p| p.game == game)
+            .cloned()
+            .collect();
+        self.suggested_preset = self
+            .monitor_size
+            .and_then(|(width, height)| suggest_preset(&self.presets, game, width, height))
+            .cloned();
+    }
+
+    /// Résolution du preset enregistré : celle de la capture, sinon celle de l'écran
+    fn preset_resolution(&self) -> (u32, u32) {
+        self.screenshot
+            .as_ref()
+            .map(|s| (s.width(), s.height()))
+            .or(self.monitor_size)
+            .unwrap_or((1920, 1080))
+    }
+
+    pub fn update(&mut self, message: ZoneEditorMessage) -> Task<ZoneEditorMessage> {
+        match message {
+            ZoneEditorMessage::Capture => {
+                let capture_mode = self.settings.get_capture_mode();
+                let screen = self.settings.get_screen();
+                let window_name = self.settings.get_window_name().clone();
+                Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || match capture_mode {
+                            CaptureMode::Window => capture_window(&window_name),
+                            _ => capture_screen(screen),
+                        })
+                        .await
+                        .map_err(|e| e.to_string())?
+                        .map(|(image, _, _)| image)
+                    },
+                    ZoneEditorMessage::ScreenshotLoaded,
+                )
+            }
+            ZoneEditorMessage::PathChanged(path) => {
+                self.path = path;
+                Task::none()
+            }
+            ZoneEditorMessage::LoadImage => {
+                let path = self.path.trim().to_string();
+                Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || {
+                            ::image::open(&path).map_err(|e| format!("{} : {}", path, e))
+                        })
+                        .await
+                        .map_err(|e| e.to_string())?
+                    },
+                    ZoneEditorMessage::ScreenshotLoaded,
+                )
+            }
+            ZoneEditorMessage::ScreenshotLoaded(result) => match result {
+                Ok(screenshot) => {
+                    self.screenshot_handle = Some(to_handle(&screenshot));
+                    self.content_area = Some(ContentArea::of(&screenshot));
+                    self.screenshot = Some(screenshot);
+                    self.error = None;
+                    self.preview_selected_zone()
+                }
+                Err(e) => {
+                    self.error = Some(e);
+                    Task::none()
+                }
+            },
+            ZoneEditorMessage::ZoneSelected(target) => {
+                self.selected = target;
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::ZoneDrawn(zone) => {
+                match self.selected {
+                    ZoneTarget::Death => self.game_config.set_death_zone(zone),
+                    ZoneTarget::Boss(index) => {
+                        let mut boss_zones = self.game_config.get_boss_zones().clone();
+                        if let Some(boss_zone) = boss_zones.get_mut(index) {
+                            *boss_zone = zone;
+                        }
+                        self.game_config.set_boss_zones(boss_zones);
+                    }
+                }
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::AddBossZone => {
+                let mut boss_zones = self.game_config.get_boss_zones().clone();
+                // Nouvelle barre au-dessus de la dernière
+                let zone = boss_zones
+                    .last()
+                    .map(|last| CropPosition {
+                        y_percent: (last.y_percent - last.height_percent).max(0.0),
+                        ..*last
+                    })
+                    .unwrap_or_else(|| CropPosition::new(24.0, 77.0, 53.0, 5.0));
+                boss_zones.push(zone);
+                self.selected = ZoneTarget::Boss(boss_zones.len() - 1);
+                self.game_config.set_boss_zones(boss_zones);
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::RemoveBossZone => {
+                let mut boss_zones = self.game_config.get_boss_zones().clone();
+                boss_zones.pop();
+                if let ZoneTarget::Boss(index) = self.selected
+                    && index >= boss_zones.len()
+                {
+                    self.selected = ZoneTarget::Death;
+                }
+                self.game_config.set_boss_zones(boss_zones);
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::PreviewReady(result) => {
+                match result {
+                    Ok((processed, text)) => {
+                        self.preview = Some(to_handle(&processed));
+                        self.ocr_text = Some(text);
+                    }
+                    Err(e) => {
+                        self.preview = None;
+                        self.ocr_text = Some(format!("❌ {}", e));
+                    }
+                }
+                Task::none()
+            }
+            ZoneEditorMessage::ResetDefaults => {
+                self.game_config = self.settings.get_default_game_config();
+                self.selected = ZoneTarget::Death;
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::PresetSelected(preset) => {
+                self.selected_preset = Some(preset);
+                Task::none()
+            }
+            ZoneEditorMessage::ApplyPreset(preset) => {
+                self.game_config = preset.config;
+                self.selected = ZoneTarget::Death;
+                self.preview_selected_zone()
+            }
+            ZoneEditorMessage::PresetNameChanged(name) => {
+                self.preset_name = name;
+                Task::none()
+            }
+            ZoneEditorMessage::SavePreset => {
+                let name = self.preset_name.trim();
+                if name.is_empty() {
+                    return Task::none();
+                }
+                let (width, height) = self.preset_resolution();
+                let preset = CropPreset::new(
+                    name,
+                    self.settings.get_game(),
+                    width,
+                    height,
+                    self.game_config.clone(),
+                );
+                self.settings.add_crop_preset(preset.clone());
+                self.settings.save();
+                self.refresh_presets();
+                self.selected_preset = Some(preset);
+                self.preset_name.clear();
+                Task::none()
+            }
+            ZoneEditorMessage::PresetPathChanged(path) => {
+                self.preset_path = path;
+                Task::none()
+            }
+            ZoneEditorMessage::ImportPresets => {
+                let path = self.preset_path.trim().to_string();
+                match import_presets(path.as_ref()) {
+                    Ok(presets) => {
+                        for preset in presets {
+                            self.settings.add_crop_preset(preset);
+                        }
+                        self.settings.save();
+                        self.refresh_presets();
+                        self.info = Some((ZoneEditorKey::PresetsImported, path));
+                        self.error = None;
+                    }
+                    Err(e) => {
+                        self.info = None;
+                        self.error = Some(e);
+                    }
+                }
+                Task::none()
+            }
+            ZoneEditorMessage::ExportPresets => {
+                let path = self.preset_path.trim().to_string();
+                match export_presets(path.as_ref(), self.settings.get_crop_presets()) {
+                    Ok(()) => {
+                        self.info = Some((ZoneEditorKey::PresetsExported, path));
+                        self.error = None;
+                    }
+                    Err(e) => {
+                        self.info = None;
+                        self.error = Some(e);
+                    }
+                }
+                Task::none()
+            }
+            ZoneEditorMessage::Save => {
+                let game = self.settings.get_game();
+                self.settings
+                    .set_custom_game_config(game, self.game_config.clone());
+                self.settings.save();
+                Task::done(ZoneEditorMessage::ChangeView(Screen::SettingsScreen(
+                    SettingsScreen::new(),
+                )))
+            }
+            ZoneEditorMessage::Back => Task::done(ZoneEditorMessage::ChangeView(
+                Screen::SettingsScreen(SettingsScreen::new()),
+            )),
+            ZoneEditorMessage::ChangeView(_) => Task::none(),
+        }
+    }
+
+    fn selected_zone(&self) -> Option<CropPosition> {
+        match self.selected {
+            ZoneTarget::Death => Some(*self.game_config.get_death_zone()),
+            ZoneTarget::Boss(index) => self.game_config.get_boss_zones().get(index).copied(),
+        }
+    }
+
+    // Prétraiter la zone sélectionnée et lancer l'OCR dessus
+    fn preview_selected_zone(&mut self) -> Task<ZoneEditorMessage> {
+        let (Some(screenshot), Some(content_area), Some(zone)) =
+            (&self.screenshot, self.content_area, self.selected_zone())
+        else {
+            return Task::none();
+        };
+        let (_, _, width, height) = zone.to_pixels_in(zone.reference_area(content_area));
+        if width == 0 || height == 0 {
+            return Task::none();
+        }
+
+        let crop = zone.crop_image(screenshot, content_area);
+        let color_profile = *self.game_config.get_color_profile();
+        let is_death_zone = self.selected == ZoneTarget::Death;
+        self.ocr_text = None;
+        Task::perform(
+            preview_zone(crop, color_profile, is_death_zone),
+            ZoneEditorMessage::PreviewReady,
+        )
+    }
+
+    pub fn view(&self, i18n: &I18n) -> Element<'_, ZoneEditorMessage> {
+        let source_row = row![
+            button(i18n.zone_editor(ZoneEditorKey::Capture)).on_press(ZoneEditorMessage::Capture),
+            text_input(i18n.zone_editor(ZoneEditorKey::ImagePathInput), &self.path)
+                .on_input(ZoneEditorMessage::PathChanged)
+                .on_submit(ZoneEditorMessage::LoadImage)
+                .width(Length::Fill),
+            button(i18n.zone_editor(ZoneEditorKey::Load)).on_press(ZoneEditorMessage::LoadImage),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let mut zone_buttons = vec![zone_button(
+            i18n.zone_editor(ZoneEditorKey::DeathZone).to_string(),
+            ZoneTarget::Death,
+            self.selected,
+        )];
+        for index in 0..self.game_config.get_boss_zones().len() {
+            zone_buttons.push(zone_button(
+                format!(
+                    "{} {}",
+                    i18n.zone_editor(ZoneEditorKey::BossZone),
+                    index + 1
+                ),
+                ZoneTarget::Boss(index),
+                self.selected,
+            ));
+        }
+        let zones_row = row![
+            row(zone_buttons).spacing(5),
+            button(i18n.zone_editor(ZoneEditorKey::AddBossZone))
+                .on_press(ZoneEditorMessage::AddBossZone),
+            button(i18n.zone_editor(ZoneEditorKey::RemoveBossZone)).on_press_maybe(
+                (!self.game_config.get_boss_zones().is_empty())
+                    .then_some(ZoneEditorMessage::RemoveBossZone)
+            ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let mut presets_column = column![
+            text(i18n.zone_editor(ZoneEditorKey::Presets)).size(18),
+            row![
+                pick_list(
+                    self.presets.clone(),
+                    self.selected_preset.clone(),
+                    ZoneEditorMessage::PresetSelected,
+                )
+                .placeholder(i18n.zone_editor(ZoneEditorKey::PresetPlaceholder))
+                .width(Length::Fill),
+                button(i18n.zone_editor(ZoneEditorKey::ApplyPreset)).on_press_maybe(
+                    self.selected_preset
+                        .clone()
+                        .map(ZoneEditorMessage::ApplyPreset)
+                ),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(5);
+        if let Some(suggested) = &self.suggested_preset {
+            presets_column = presets_column.push(
+                row![
+                    text(format!(
+                        "{} {}",
+                        i18n.zone_editor(ZoneEditorKey::SuggestedPreset),
+                        suggested
+                    )),
+                    button(i18n.zone_editor(ZoneEditorKey::ApplyPreset))
+                        .on_press(ZoneEditorMessage::ApplyPreset(suggested.clone())),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        presets_column = presets_column
+            .push(
+                row![
+                    text_input(
+                        i18n.zone_editor(ZoneEditorKey::PresetNameInput),
+                        &self.preset_name
+                    )
+                    .on_input(ZoneEditorMessage::PresetNameChanged)
+                    .on_submit(ZoneEditorMessage::SavePreset)
+                    .width(Length::Fill),
+                    button(i18n.zone_editor(ZoneEditorKey::SavePreset)).on_press_maybe(
+                        (!self.preset_name.trim().is_empty())
+                            .then_some(ZoneEditorMessage::SavePreset)
+                    ),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+            .push(
+                row![
+                    text_input(
+                        i18n.zone_editor(ZoneEditorKey::PresetFileInput),
+                        &self.preset_path
+                    )
+                    .on_input(ZoneEditorMessage::PresetPathChanged)
+                    .width(Length::Fill),
+                    button(i18n.zone_editor(ZoneEditorKey::ImportPresets)).on_press_maybe(
+                        (!self.preset_path.trim().is_empty())
+                            .then_some(ZoneEditorMessage::ImportPresets)
+                    ),
+                    button(i18n.zone_editor(ZoneEditorKey::ExportPresets)).on_press_maybe(
+                        (!self.preset_path.trim().is_empty())
+                            .then_some(ZoneEditorMessage::ExportPresets)
+                    ),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        if let Some((key, path)) = &self.info {
+            presets_column =
+                presets_column.push(text(format!("{} {}", i18n.zone_editor(*key), path)));
+        }
+
+        let editor: Element<ZoneEditorMessage> =
+            match (&self.screenshot, &self.screenshot_handle, self.content_area) {
+                (Some(screenshot), Some(handle), Some(content_area)) => {
+                    let height = EDITOR_WIDTH * screenshot.height() as f32
+                        / screenshot.width().max(1) as f32;
+                    let zone_canvas = ZoneCanvas {
+                        game_config: &self.game_config,
+                        selected: self.selected,
+                        frame: Size::new(screenshot.width() as f32, screenshot.height() as f32),
+                        content_area,
+                    };
+                    column![
+                        text(i18n.zone_editor(ZoneEditorKey::DrawHint)).size(14),
+                        container(stack![
+                            image(handle.clone())
+                                .width(Length::Fill)
+                                .height(Length::Fill)
+                                .content_fit(ContentFit::Fill),
+                            Canvas::new(zone_canvas)
+                                .width(Length::Fill)
+                                .height(Length::Fill),
+                        ])
+                        .width(EDITOR_WIDTH)
+                        .height(height),
+                    ]
+                    .spacing(5)
+                    .into()
+                }
+                _ => text(i18n.zone_editor(ZoneEditorKey::NoScreenshot)).into(),
+            };
+
+        let mut preview_column =
+            column![text(i18n.zone_editor(ZoneEditorKey::Preview)).size(18)].spacing(5);
+        if let Some(preview) = &self.preview {
+            preview_column = preview_column.push(
+                image(preview.clone())
+                    .width(Length::Fill)
+                    .content_fit(ContentFit::Contain),
+            );
+        }
+        if let Some(ocr_text) = &self.ocr_text {
+            preview_column = preview_column.push(text(format!(
+                "{} {}",
+                i18n.zone_editor(ZoneEditorKey::OcrText),
+                ocr_text
+            )));
+        }
+
+        let mut content = column![
+            text(format!(
+                "{} - {}",
+                i18n.zone_editor(ZoneEditorKey::Title),
+                self.settings.get_game()
+            ))
+            .size(28),
+            source_row,
+            presets_column,
+            zones_row,
+            editor,
+            preview_column,
+            row![
+                button(i18n.general(GeneralKey::Back)).on_press(ZoneEditorMessage::Back),
+                button(i18n.zone_editor(ZoneEditorKey::Reset))
+                    .on_press(ZoneEditorMessage::ResetDefaults),
+                button(i18n.general(GeneralKey::Save)).on_press(ZoneEditorMessage::Save),
+            ]
+            .spacing(10),
+        ]
+        .spacing(15)
+        .padding(20);
+
+        if let Some(error) = &self.error {
+            content = content.push(text(error).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        scrollable(content).height(Length::Fill).into()
+    }
+
+    pub fn subscription(&self) -> Subscription<ZoneEditorMessage> {
+        Subscription::none()
+    }
+}
+
+fn zone_button<'a>(
+    label: String,
+    target: ZoneTarget,
+    selected: ZoneTarget,
+) -> Element<'a, ZoneEditorMessage> {
+    button(text(label))
+        .style(if target == selected {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .on_press(ZoneEditorMessage::ZoneSelected(target))
+        .into()
+}
+
+fn to_handle(image: &DynamicImage) -> image::Handle {
+    let rgba = image.to_rgba8();
+    image::Handle::from_rgba(rgba.width(), rgba.height(), rgba.into_raw())
+}
+
+// -------------------------------------------------------
+// Canvas : zones dessinées par-dessus la capture
+// -------------------------------------------------------
+struct ZoneCanvas<'a> {
+    game_config: &'a GameConfig,
+    selected: ZoneTarget,
+    /// Taille de la capture en pixels
+    frame: Size,
+    content_area: ContentArea,
+}
+
+/// Glisser en cours (coordonnées relatives au canvas)
+#[derive(Default)]
+struct DragState {
+    start: Option<Point>,
+    current: Option<Point>,
+}
+
+impl canvas::Program<ZoneEditorMessage> for ZoneCanvas<'_> {
+    type State = DragState;
+
+    fn update(
+        &self,
+        state: &mut DragState,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<ZoneEditorMessage>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let position = cursor.position_in(bounds)?;
+                state.start = Some(position);
+                state.current = Some(position);
+                Some(canvas::Action::request_redraw().and_capture())
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if state.start.is_some() => {
+                state.current = cursor.position_from(bounds.position());
+                Some(canvas::Action::request_redraw())
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let start = state.start.take()?;
+                let end = state.current.take().unwrap_or(start);
+                let zone = self.rectangle_to_zone(start, end, bounds.size())?;
+                Some(canvas::Action::publish(ZoneEditorMessage::ZoneDrawn(zone)).and_capture())
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &DragState,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        // Interface 16:9 de référence (hors bandes noires et marges ultrawide)
+        let ui_area = self.content_area.with_aspect(UI_ASPECT_16_9);
+        let (top_left, size) = self.pixels_to_canvas(
+            (ui_area.x, ui_area.y, ui_area.width, ui_area.height),
+            bounds.size(),
+        );
+        frame.stroke(
+            &Path::rectangle(top_left, size),
+            Stroke::default()
+                .with_color(Color::from_rgba(1.0, 1.0, 1.0, 0.4))
+                .with_width(1.0),
+        );
+
+        let zones = std::iter::once((ZoneTarget::Death, *self.game_config.get_death_zone())).chain(
+            self.game_config
+                .get_boss_zones()
+                .iter()
+                .enumerate()
+                .map(|(index, zone)| (ZoneTarget::Boss(index), *zone)),
+        );
+        for (target, zone) in zones {
+            let (color, width) = if target == self.selected {
+                (SELECTED_ZONE_COLOR, 3.0)
+            } else if target == ZoneTarget::Death {
+                (DEATH_ZONE_COLOR, 2.0)
+            } else {
+                (BOSS_ZONE_COLOR, 2.0)
+            };
+            let pixels = zone.to_pixels_in(zone.reference_area(self.content_area));
+            let (top_left, size) = self.pixels_to_canvas(pixels, bounds.size());
+            frame.stroke(
+                &Path::rectangle(top_left, size),
+                Stroke::default().with_color(color).with_width(width),
+            );
+        }
+
+        // Zone en cours de tracé
+        if let (Some(start), Some(current)) = (state.start, state.current) {
+            let top_left = Point::new(start.x.min(current.x), start.y.min(current.y));
+            let size = Size::new((current.x - start.x).abs(), (current.y - start.y).abs());
+            frame.fill_rectangle(top_left, size, Color::from_rgba(1.0, 0.85, 0.2, 0.25));
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &DragState,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl ZoneCanvas<'_> {
+    /// Rectangle en pixels de la capture -> coordonnées du canvas
+    fn pixels_to_canvas(
+        &self,
+        (x, y, width, height): (u32, u32, u32, u32),
+        canvas: Size,
+    ) -> (Point, Size) {
+        let scale_x = canvas.width / self.frame.width.max(1.0);
+        let scale_y = canvas.height / self.frame.height.max(1.0);
+        (
+            Point::new(x as f32 * scale_x, y as f32 * scale_y),
+            Size::new(width as f32 * scale_x, height as f32 * scale_y),
+        )
+    }
+
+    /// Convertir un rectangle tracé en zone relative à l'interface 16:9, `None` s'il est vide
+    fn rectangle_to_zone(&self, start: Point, end: Point, canvas: Size) -> Option<CropPosition> {
+        if canvas.width <= 0.0 || canvas.height <= 0.0 {
+            return None;
+        }
+        let to_pixel = |value: f32, canvas_size: f32, frame_size: f32| {
+            (value / canvas_size * frame_size).clamp(0.0, frame_size) as u32
+        };
+
+        let x = to_pixel(start.x.min(end.x), canvas.width, self.frame.width);
+        let y = to_pixel(start.y.min(end.y), canvas.height, self.frame.height);
+        let right = to_pixel(start.x.max(end.x), canvas.width, self.frame.width);
+        let bottom = to_pixel(start.y.max(end.y), canvas.height, self.frame.height);
+        if right < x + 2 || bottom < y + 2 {
+            return None;
+        }
+        let area = self.content_area.with_aspect(UI_ASPECT_16_9);
+        Some(CropPosition::from_pixels(x, y, right - x, bottom - y, area))
+    }
+}
@@ -1,7 +1,10 @@
 use crate::i18n::translations::I18n;
 use crate::screens::add_recorder_screen::{AddRecorderMessage, AddRecorderScreen};
+use crate::screens::log_screen::{LogMessage, LogScreen};
 use crate::screens::main_screen::{MainScreen, MainScreenMessage};
 use crate::screens::settings_screen::{SettingsScreen, SettingsScreenMessage};
+use crate::screens::vod_screen::{VodMessage, VodScreen};
+use crate::screens::zone_editor_screen::{ZoneEditorMessage, ZoneEditorScreen};
 use crate::utils::app_worker::hotkey_subscription;
 use iced::task::Task;
 use iced::{Element, Subscription};
@@ -11,6 +14,9 @@ pub enum MessageApp {
     MainScreen(MainScreenMessage),
     AddRecorderScreen(AddRecorderMessage),
     SettingsScreen(SettingsScreenMessage),
+    VodScreen(VodMessage),
+    ZoneEditorScreen(ZoneEditorMessage),
+    LogScreen(LogMessage),
 }
 
 #[derive(Clone, Debug)]
@@ -18,6 +24,9 @@ pub enum Screen {
     MainScreen(MainScreen),
     AddRecorderScreen(AddRecorderScreen),
     SettingsScreen(SettingsScreen),
+    VodScreen(VodScreen),
+    ZoneEditorScreen(ZoneEditorScreen),
+    LogScreen(LogScreen),
 }
 
 impl Default for Screen {
@@ -100,6 +109,45 @@ impl App {
                     _ => Task::none(),
                 },
             },
+
+            MessageApp::VodScreen(vod_message) => match vod_message {
+                VodMessage::ChangeView(screen) => {
+                    self.go_to(screen);
+                    Task::none()
+                }
+                _ => match &mut self.screen {
+                    Screen::VodScreen(vod_screen) => {
+                        vod_screen.update(vod_message).map(MessageApp::VodScreen)
+                    }
+                    _ => Task::none(),
+                },
+            },
+
+            MessageApp::ZoneEditorScreen(zone_editor_message) => match zone_editor_message {
+                ZoneEditorMessage::ChangeView(screen) => {
+                    self.go_to(screen);
+                    Task::none()
+                }
+                _ => match &mut self.screen {
+                    Screen::ZoneEditorScreen(zone_editor_screen) => zone_editor_screen
+                        .update(zone_editor_message)
+                        .map(MessageApp::ZoneEditorScreen),
+                    _ => Task::none(),
+                },
+            },
+
+            MessageApp::LogScreen(log_message) => match log_message {
+                LogMessage::ChangeView(screen) => {
+                    self.go_to(screen);
+                    Task::none()
+                }
+                _ => match &mut self.screen {
+                    Screen::LogScreen(log_screen) => {
+                        log_screen.update(log_message).map(MessageApp::LogScreen)
+                    }
+                    _ => Task::none(),
+                },
+            },
         }
     }
 
@@ -114,6 +162,11 @@ impl App {
             Screen::SettingsScreen(settings_screen) => settings_screen
                 .view(&self.i18n)
                 .map(MessageApp::SettingsScreen),
+            Screen::VodScreen(vod_screen) => vod_screen.view(&self.i18n).map(MessageApp::VodScreen),
+            Screen::ZoneEditorScreen(zone_editor_screen) => zone_editor_screen
+                .view(&self.i18n)
+                .map(MessageApp::ZoneEditorScreen),
+            Screen::LogScreen(log_screen) => log_screen.view(&self.i18n).map(MessageApp::LogScreen),
         };
         main
     }
@@ -135,6 +188,14 @@ impl App {
             Screen::SettingsScreen(settings_screen) => settings_screen
                 .subscription()
                 .map(MessageApp::SettingsScreen),
+
+            Screen::VodScreen(vod_screen) => vod_screen.subscription().map(MessageApp::VodScreen),
+
+            Screen::ZoneEditorScreen(zone_editor_screen) => zone_editor_screen
+                .subscription()
+                .map(MessageApp::ZoneEditorScreen),
+
+            Screen::LogScreen(log_screen) => log_screen.subscription().map(MessageApp::LogScreen),
         };
 
         Subscription::batch(vec![hotkey_sub, sub])
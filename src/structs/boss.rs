@@ -0,0 +1,298 @@
+// boss.rs - Catalogue des boss : identifiant canonique + nom dans chaque langue
+//
+// Les titres lus par l'OCR dépendent de la langue du jeu ; l'identifiant permet de garder
+// un seul compteur par boss et d'afficher son nom dans la langue de l'application.
+
+use crate::i18n::language::Language;
+use strsim::normalized_levenshtein;
+
+/// Similarité minimale entre un nom lu et un nom du catalogue
+const CATALOG_SIMILARITY: f64 = 0.85;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BossEntry {
+    pub id: &'static str,
+    pub fr: &'static str,
+    pub en: &'static str,
+}
+
+/// Combat à plusieurs boss (identifiants de `BOSSES`)
+#[derive(Debug, Clone, Copy)]
+pub struct EncounterEntry {
+    pub id: &'static str,
+    pub fr: &'static str,
+    pub en: &'static str,
+    pub bosses: &'static [&'static str],
+}
+
+pub const BOSSES: &[BossEntry] = &[
+    // Elden Ring
+    boss("margit", "Margit, le Déchu", "Margit, the Fell Omen"),
+    boss("godrick", "Godrick le Greffé", "Godrick the Grafted"),
+    boss("tree_sentinel", "Sentinelle de l'arbre", "Tree Sentinel"),
+    boss(
+        "red_wolf_radagon",
+        "Loup rouge de Radagon",
+        "Red Wolf of Radagon",
+    ),
+    boss(
+        "rennala",
+        "Rennala, reine de la pleine lune",
+        "Rennala, Queen of the Full Moon",
+    ),
+    boss("radahn", "Radahn, fléau des étoiles", "Starscourge Radahn"),
+    boss(
+        "leonine_misbegotten",
+        "Mal-né léonin",
+        "Leonine Misbegotten",
+    ),
+    boss(
+        "rykard",
+        "Rykard, seigneur du blasphème",
+        "Rykard, Lord of Blasphemy",
+    ),
+    boss(
+        "morgott",
+        "Morgott, le roi réprouvé",
+        "Morgott, the Omen King",
+    ),
+    boss("fire_giant", "Géant de feu", "Fire Giant"),
+    boss("mimic_tear", "Larme mimétique", "Mimic Tear"),
+    boss("commander_niall", "Commandant Niall", "Commander Niall"),
+    boss(
+        "astel",
+        "Astel, Engeance du Vide",
+        "Astel, Naturalborn of the Void",
+    ),
+    boss(
+        "malenia",
+        "Malenia, lame de Miquella",
+        "Malenia, Blade of Miquella",
+    ),
+    boss("mohg", "Mohg, seigneur du sang", "Mohg, Lord of Blood"),
+    boss(
+        "maliketh",
+        "Maliketh, la lame noire",
+        "Maliketh, the Black Blade",
+    ),
+    boss(
+        "godfrey",
+        "Godfrey, premier seigneur d'Elden",
+        "Godfrey, First Elden Lord",
+    ),
+    boss(
+        "hoarah_loux",
+        "Hoarah Loux, guerrier",
+        "Hoarah Loux, Warrior",
+    ),
+    boss(
+        "placidusax",
+        "Placidusax, seigneur dragon",
+        "Dragonlord Placidusax",
+    ),
+    boss(
+        "radagon",
+        "Radagon de l'ordre d'or",
+        "Radagon of the Golden Order",
+    ),
+    boss("elden_beast", "Bête d'Elden", "Elden Beast"),
+    boss(
+        "godskin_apostle",
+        "Apôtre de la Peau divine",
+        "Godskin Apostle",
+    ),
+    boss("godskin_noble", "Noble de la Peau divine", "Godskin Noble"),
+    boss(
+        "valiant_gargoyle",
+        "Gargouille vaillante",
+        "Valiant Gargoyle",
+    ),
+    boss("crucible_knight", "Chevalier du Creuset", "Crucible Knight"),
+    boss(
+        "misbegotten_warrior",
+        "Guerrier mal-né",
+        "Misbegotten Warrior",
+    ),
+    // Dark Souls
+    boss(
+        "ornstein",
+        "Ornstein le Tueur de dragons",
+        "Dragon Slayer Ornstein",
+    ),
+    boss("smough", "Smough le Bourreau", "Executioner Smough"),
+    // Dark Souls III
+    boss(
+        "lothric",
+        "Lothric, le jeune prince",
+        "Lothric, Younger Prince",
+    ),
+    boss("lorian", "Lorian, le prince aîné", "Lorian, Elder Prince"),
+    boss("demon_in_pain", "Démon souffrant", "Demon in Pain"),
+    boss(
+        "demon_from_below",
+        "Démon des profondeurs",
+        "Demon from Below",
+    ),
+    boss("soul_of_cinder", "Âme des cendres", "Soul of Cinder"),
+    // Sekiro
+    boss("genichiro", "Genichiro Ashina", "Genichiro Ashina"),
+    boss(
+        "isshin_sword_saint",
+        "Isshin, le Saint du sabre",
+        "Isshin, the Sword Saint",
+    ),
+];
+
+pub const ENCOUNTERS: &[EncounterEntry] = &[
+    EncounterEntry {
+        id: "godskin_duo",
+        fr: "Duo de la Peau divine",
+        en: "Godskin Duo",
+        bosses: &["godskin_apostle", "godskin_noble"],
+    },
+    EncounterEntry {
+        id: "valiant_gargoyles",
+        fr: "Gargouilles vaillantes",
+        en: "Valiant Gargoyles",
+        bosses: &["valiant_gargoyle", "valiant_gargoyle"],
+    },
+    EncounterEntry {
+        id: "crucible_knight_misbegotten_warrior",
+        fr: "Chevalier du Creuset & Guerrier mal-né",
+        en: "Crucible Knight & Misbegotten Warrior",
+        bosses: &["crucible_knight", "misbegotten_warrior"],
+    },
+    EncounterEntry {
+        id: "ornstein_smough",
+        fr: "Ornstein & Smough",
+        en: "Ornstein & Smough",
+        bosses: &["ornstein", "smough"],
+    },
+    EncounterEntry {
+        id: "twin_princes",
+        fr: "Princes jumeaux",
+        en: "Twin Princes",
+        bosses: &["lothric", "lorian"],
+    },
+    EncounterEntry {
+        id: "demon_prince",
+        fr: "Prince démon",
+        en: "Demon Prince",
+        bosses: &["demon_in_pain", "demon_from_below"],
+    },
+];
+
+const fn boss(id: &'static str, fr: &'static str, en: &'static str) -> BossEntry {
+    BossEntry { id, fr, en }
+}
+
+impl BossEntry {
+    pub fn name(&self, language: &Language) -> &'static str {
+        match language {
+            Language::French => self.fr,
+            Language::English => self.en,
+        }
+    }
+}
+
+impl EncounterEntry {
+    pub fn name(&self, language: &Language) -> &'static str {
+        match language {
+            Language::French => self.fr,
+            Language::English => self.en,
+        }
+    }
+}
+
+pub fn find_boss(id: &str) -> Option<&'static BossEntry> {
+    BOSSES.iter().find(|b| b.id == id)
+}
+
+/// Tous les noms connus (toutes langues) d'un identifiant
+fn names_of(id: &str) -> Vec<&'static str> {
+    BOSSES
+        .iter()
+        .filter(|b| b.id == id)
+        .flat_map(|b| [b.fr, b.en])
+        .chain(
+            ENCOUNTERS
+                .iter()
+                .filter(|e| e.id == id)
+                .flat_map(|e| [e.fr, e.en]),
+        )
+        .collect()
+}
+
+/// Identifiant canonique d'un nom lu par l'OCR (dans n'importe quelle langue)
+pub fn find_boss_id(name: &str) -> Option<&'static str> {
+    let name = name.trim().to_uppercase();
+    if name.is_empty() {
+        return None;
+    }
+
+    let candidates = BOSSES
+        .iter()
+        .flat_map(|b| [(b.id, b.fr), (b.id, b.en)])
+        .chain(ENCOUNTERS.iter().flat_map(|e| [(e.id, e.fr), (e.id, e.en)]));
+
+    candidates
+        .map(|(id, known)| (id, normalized_levenshtein(&name, &known.to_uppercase())))
+        .filter(|(_, score)| *score >= CATALOG_SIMILARITY)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(id, _)| id)
+}
+
+/// Nom d'un boss ou d'une rencontre dans la langue de l'application
+pub fn display_name(id: &str, language: &Language) -> Option<&'static str> {
+    find_boss(id).map(|b| b.name(language)).or_else(|| {
+        ENCOUNTERS
+            .iter()
+            .find(|e| e.id == id)
+            .map(|e| e.name(language))
+    })
+}
+
+/// Le titre est-il un des noms du catalogue pour cet identifiant (et non un titre personnalisé) ?
+pub fn is_catalog_name(id: &str, title: &str) -> bool {
+    let title = title.trim().to_uppercase();
+    names_of(id)
+        .iter()
+        .any(|name| normalized_levenshtein(&title, &name.to_uppercase()) >= CATALOG_SIMILARITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ocr_names_in_any_language_share_the_same_id() {
+        assert_eq!(find_boss_id("MARGIT, LE DECHU"), Some("margit"));
+        assert_eq!(find_boss_id("Margit, the Fell 0men"), Some("margit"));
+        assert_eq!(find_boss_id("Godskin Duo"), Some("godskin_duo"));
+        assert_eq!(find_boss_id("Boss inconnu"), None);
+
+        assert_eq!(
+            display_name("margit", &Language::English),
+            Some("Margit, the Fell Omen")
+        );
+        assert!(is_catalog_name("margit", "MARGIT, LE DÉCHU"));
+        assert!(!is_catalog_name("margit", "Mon nemesis"));
+    }
+
+    #[test]
+    fn catalog_ids_are_unique_and_encounters_reference_known_bosses() {
+        let mut ids: Vec<&str> = BOSSES
+            .iter()
+            .map(|b| b.id)
+            .chain(ENCOUNTERS.iter().map(|e| e.id))
+            .collect();
+        let count = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), count);
+
+        for encounter in ENCOUNTERS {
+            assert!(encounter.bosses.iter().all(|id| find_boss(id).is_some()));
+        }
+    }
+}
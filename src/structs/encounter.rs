@@ -0,0 +1,146 @@
+use crate::i18n::language::ALL_LANGUAGES;
+use crate::structs::boss::{ENCOUNTERS, find_boss};
+use serde::{Deserialize, Serialize};
+use strsim::normalized_levenshtein;
+
+/// Séparateur entre les noms de boss dans le titre d'un compteur
+pub const BOSS_SEPARATOR: &str = " - ";
+/// Similarité minimale entre un nom lu par l'OCR et un nom de la rencontre
+const BOSS_SIMILARITY: f64 = 0.80;
+
+/// Combat à plusieurs boss compté comme un seul compteur
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Encounter {
+    /// Titre du compteur
+    pub name: String,
+    /// Noms des barres de boss, dans n'importe quel ordre
+    pub bosses: Vec<String>,
+}
+
+impl Encounter {
+    pub fn new(name: &str, bosses: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            bosses: bosses.iter().map(|b| b.to_string()).collect(),
+        }
+    }
+
+    /// Mêmes boss que la rencontre, quel que soit l'ordre des barres
+    pub fn matches(&self, found: &[String]) -> bool {
+        same_bosses(&self.bosses, found)
+    }
+}
+
+/// Rencontres connues, dans chaque langue (générées depuis le catalogue des boss)
+pub fn builtin_encounters() -> Vec<Encounter> {
+    ENCOUNTERS
+        .iter()
+        .flat_map(|entry| {
+            ALL_LANGUAGES.iter().filter_map(move |language| {
+                let bosses: Option<Vec<String>> = entry
+                    .bosses
+                    .iter()
+                    .map(|id| find_boss(id).map(|b| b.name(language).to_string()))
+                    .collect();
+                Some(Encounter {
+                    name: entry.name(language).to_string(),
+                    bosses: bosses?,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Titre du compteur pour les boss lus : la rencontre connue, sinon les noms
+/// triés par ordre alphabétique (indépendant de l'ordre des barres)
+pub fn resolve_encounter(found: &[String], encounters: &[Encounter]) -> String {
+    let found: Vec<String> = found
+        .iter()
+        .map(|b| b.trim().to_string())
+        .filter(|b| !b.is_empty())
+        .collect();
+
+    if found.len() > 1
+        && let Some(encounter) = encounters.iter().find(|e| e.matches(&found))
+    {
+        return encounter.name.clone();
+    }
+
+    let mut sorted = found;
+    sorted.sort_by_key(|b| b.to_uppercase());
+    sorted.join(BOSS_SEPARATOR)
+}
+
+/// Deux titres de compteur désignent-ils le même combat ? (ordre des boss indifférent)
+pub fn same_fight(a: &str, b: &str) -> bool {
+    let split = |title: &str| -> Vec<String> {
+        title
+            .split(BOSS_SEPARATOR)
+            .map(|s| s.trim().to_string())
+            .collect()
+    };
+    same_bosses(&split(a), &split(b))
+}
+
+/// Comparaison en multi-ensemble avec tolérance aux erreurs d'OCR
+pub fn same_bosses(expected: &[String], found: &[String]) -> bool {
+    if expected.len() != found.len() {
+        return false;
+    }
+
+    let mut remaining: Vec<String> = found.iter().map(|b| b.to_uppercase()).collect();
+    expected.iter().all(|name| {
+        let name = name.to_uppercase();
+        let best = remaining
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (i, normalized_levenshtein(&name, f)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        match best {
+            Some((i, score)) if score >= BOSS_SIMILARITY => {
+                remaining.remove(i);
+                true
+            }
+            _ => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn encounter_matches_in_any_bar_order_with_ocr_noise() {
+        let encounters = builtin_encounters();
+
+        assert_eq!(
+            resolve_encounter(&names(&["Godskin Noble", "Godskin Apostle"]), &encounters),
+            "Godskin Duo"
+        );
+        assert_eq!(
+            resolve_encounter(&names(&["Godskin Apostie", "GODSKIN NOBLE"]), &encounters),
+            "Godskin Duo"
+        );
+        // Une seule gargouille ne suffit pas
+        assert_eq!(
+            resolve_encounter(&names(&["Valiant Gargoyle"]), &encounters),
+            "Valiant Gargoyle"
+        );
+    }
+
+    #[test]
+    fn unknown_fights_get_an_order_independent_title() {
+        let a = resolve_encounter(&names(&["Zeta", "Alpha", ""]), &[]);
+        let b = resolve_encounter(&names(&["Alpha", "Zeta"]), &[]);
+
+        assert_eq!(a, "Alpha - Zeta");
+        assert_eq!(a, b);
+        assert!(same_fight("Zeta - Alpha", "ALPHA - Zeta"));
+        assert!(!same_fight("Zeta - Alpha", "Zeta"));
+    }
+}
@@ -1,4 +1,7 @@
 pub mod app;
+pub mod boss;
+pub mod encounter;
 pub mod recorder;
+pub mod run;
 pub mod settings;
 pub mod storage;
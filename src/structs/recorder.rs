@@ -1,3 +1,5 @@
+use crate::i18n::language::Language;
+use crate::structs::boss::{display_name, find_boss_id, is_catalog_name};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -7,6 +9,15 @@ pub enum RecorderType {
     GlobalBosses, // Compteur global de morts contre des boss uniquement
 }
 
+/// Une mort contre le boss avec les PV qui lui restaient
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Attempt {
+    /// Numéro de la mort (valeur du compteur)
+    pub death: u32,
+    /// PV restants du boss (0.0 – 1.0)
+    pub hp_left: f32,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Recorder {
     uuid: Uuid,
@@ -14,16 +25,25 @@ pub struct Recorder {
     counter: u32,
     active: bool,
     recorder_type: RecorderType,
+    /// Identifiant canonique du boss (catalogue), indépendant de la langue du jeu
+    #[serde(default)]
+    boss_id: Option<String>,
+    /// Tentatives dont les PV restants du boss ont été mesurés
+    #[serde(default)]
+    attempts: Vec<Attempt>,
 }
 
 impl Recorder {
     pub fn new(title: String) -> Recorder {
+        let boss_id = find_boss_id(&title).map(str::to_string);
         Recorder {
             uuid: Uuid::new_v4(),
             title,
             counter: 0,
             active: true,
             recorder_type: RecorderType::Classic,
+            boss_id,
+            attempts: Vec::new(),
         }
     }
     // Créer le compteur global des morts
@@ -34,6 +54,8 @@ impl Recorder {
             uuid: Uuid::from_u128(1), // UUID spécial
             active: true,
             recorder_type: RecorderType::GlobalDeaths,
+            boss_id: None,
+            attempts: Vec::new(),
         }
     }
 
@@ -45,6 +67,8 @@ impl Recorder {
             uuid: Uuid::from_u128(2), // UUID spécial
             active: true,
             recorder_type: RecorderType::GlobalBosses,
+            boss_id: None,
+            attempts: Vec::new(),
         }
     }
 
@@ -52,10 +76,12 @@ impl Recorder {
         &self.uuid
     }
 
-    pub fn increment(&mut self) -> () {
+    /// Compter une mort si le compteur est actif ; renvoie vrai si elle a été comptée
+    pub fn increment(&mut self) -> bool {
         if self.active {
             self.counter += 1;
         }
+        self.active
     }
     pub fn force_increment(&mut self) -> () {
         self.counter += 1;
@@ -70,15 +96,52 @@ impl Recorder {
     }
     pub fn reset(&mut self) {
         self.counter = 0;
+        self.attempts.clear();
+    }
+
+    /// PV restants du boss lors de la dernière mort comptée
+    pub fn add_attempt(&mut self, hp_left: f32) {
+        self.attempts.push(Attempt {
+            death: self.counter,
+            hp_left: hp_left.clamp(0.0, 1.0),
+        });
+    }
+    pub fn get_attempts(&self) -> &Vec<Attempt> {
+        &self.attempts
+    }
+    /// Meilleure tentative : le moins de PV restants au boss
+    pub fn best_attempt(&self) -> Option<&Attempt> {
+        self.attempts
+            .iter()
+            .min_by(|a, b| a.hp_left.total_cmp(&b.hp_left))
     }
 
     pub fn set_title(&mut self, title: String) {
+        // Un titre personnalisé garde le boss associé
+        if let Some(id) = find_boss_id(&title) {
+            self.boss_id = Some(id.to_string());
+        }
         self.title = title;
     }
     pub fn get_title(&self) -> String {
         self.title.clone()
     }
 
+    pub fn get_boss_id(&self) -> Option<&String> {
+        self.boss_id.as_ref()
+    }
+
+    /// Titre affiché : le nom du boss dans la langue de l'application,
+    /// sauf si l'utilisateur a personnalisé le titre
+    pub fn get_display_title(&self, language: &Language) -> String {
+        match &self.boss_id {
+            Some(id) if is_catalog_name(id, &self.title) => display_name(id, language)
+                .map(str::to_string)
+                .unwrap_or_else(|| self.title.clone()),
+            _ => self.title.clone(),
+        }
+    }
+
     pub fn activate_deactivate(&mut self) {
         self.active = !self.active
     }
@@ -109,14 +172,23 @@ impl Recorder {
         counter: u32,
         is_active: bool,
         recorder_type: RecorderType,
+        boss_id: Option<String>,
+        attempts: Vec<Attempt>,
     ) -> Self {
         let uuid = Uuid::parse_str(&uuid_string).unwrap();
+        // Compteurs créés avant le catalogue : retrouver le boss depuis le titre
+        let boss_id = match recorder_type {
+            RecorderType::Classic => boss_id.or_else(|| find_boss_id(&title).map(str::to_string)),
+            _ => None,
+        };
         Recorder {
             uuid,
             title,
             counter,
             active: is_active,
             recorder_type: recorder_type,
+            boss_id,
+            attempts,
         }
     }
 }
@@ -155,9 +227,9 @@ mod tests {
     fn increment_only_when_active() {
         let mut r = Recorder::new("Test".to_string());
 
-        r.increment();
+        assert!(r.increment());
         r.activate_deactivate();
-        r.increment();
+        assert!(!r.increment());
 
         assert_eq!(r.get_counter(), 1);
     }
@@ -172,4 +244,35 @@ mod tests {
         r.activate_deactivate();
         assert!(r.get_status_recorder());
     }
+
+    #[test]
+    fn boss_title_is_displayed_in_app_language() {
+        let mut r = Recorder::new("MARGIT, LE DÉCHU".to_string());
+        assert_eq!(r.get_boss_id().map(String::as_str), Some("margit"));
+        assert_eq!(
+            r.get_display_title(&Language::English),
+            "Margit, the Fell Omen"
+        );
+
+        // Titre personnalisé : affiché tel quel, le boss reste associé
+        r.set_title("Mon némésis".to_string());
+        assert_eq!(r.get_display_title(&Language::French), "Mon némésis");
+        assert_eq!(r.get_boss_id().map(String::as_str), Some("margit"));
+    }
+
+    #[test]
+    fn best_attempt_is_the_lowest_boss_health() {
+        let mut r = Recorder::new("Margit".to_string());
+        assert!(r.best_attempt().is_none());
+
+        for hp_left in [0.8, 0.12, 0.4] {
+            r.increment();
+            r.add_attempt(hp_left);
+        }
+        let best = r.best_attempt().unwrap();
+        assert_eq!((best.death, best.hp_left), (2, 0.12));
+
+        r.reset();
+        assert!(r.get_attempts().is_empty());
+    }
 }
@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use strsim::normalized_levenshtein;
+use uuid::Uuid;
+
+/// Seigneurs de la nuit de Nightreign (boss final de chaque expédition)
+pub const NIGHTLORDS: &[&str] = &[
+    "Gladius", "Adel", "Gnoster", "Maris", "Libra", "Fulghor", "Caligo", "Heolstor",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RunOutcome {
+    InProgress,
+    Victory, // Seigneur de la nuit vaincu
+    Defeat,  // Équipe entière tombée
+    Abandoned,
+}
+
+/// Une mort pendant l'expédition
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RunDeath {
+    /// Secondes écoulées depuis le début de l'expédition
+    pub elapsed_secs: u64,
+    pub bosses: Vec<String>,
+    /// PV restants (0.0 – 1.0) de chaque barre de boss juste avant la mort
+    #[serde(default)]
+    pub boss_health: Vec<f32>,
+}
+
+/// Une expédition Nightreign : une session avec ses propres morts et boss de nuit
+#[derive(Debug, Clone)]
+pub struct Run {
+    uuid: Uuid,
+    started_at: u64,
+    ended_at: Option<u64>,
+    nightlord: Option<String>,
+    night_bosses: Vec<String>,
+    deaths: Vec<RunDeath>,
+    outcome: RunOutcome,
+}
+
+impl Run {
+    pub fn new() -> Self {
+        Run {
+            uuid: Uuid::new_v4(),
+            started_at: now_secs(),
+            ended_at: None,
+            nightlord: None,
+            night_bosses: Vec::new(),
+            deaths: Vec::new(),
+            outcome: RunOutcome::InProgress,
+        }
+    }
+
+    pub fn from_db(
+        uuid_string: String,
+        started_at: u64,
+        ended_at: Option<u64>,
+        nightlord: Option<String>,
+        night_bosses: Vec<String>,
+        deaths: Vec<RunDeath>,
+        outcome: RunOutcome,
+    ) -> Self {
+        let uuid = Uuid::parse_str(&uuid_string).unwrap_or_else(|_| Uuid::new_v4());
+        Run {
+            uuid,
+            started_at,
+            ended_at,
+            nightlord,
+            night_bosses,
+            deaths,
+            outcome,
+        }
+    }
+
+    /// Enregistrer une mort, les boss lus deviennent des boss de nuit de l'expédition
+    pub fn add_death(&mut self, bosses: Vec<String>) {
+        for boss in &bosses {
+            self.add_night_boss(boss);
+        }
+        self.deaths.push(RunDeath {
+            elapsed_secs: now_secs().saturating_sub(self.started_at),
+            bosses,
+            boss_health: Vec::new(),
+        });
+    }
+
+    /// Rattacher les boss lus après coup à une mort déjà enregistrée
+    pub fn attribute_death(&mut self, index: usize, bosses: Vec<String>, boss_health: Vec<f32>) {
+        for boss in &bosses {
+            self.add_night_boss(boss);
+        }
+        if let Some(death) = self.deaths.get_mut(index) {
+            death.bosses = bosses;
+            death.boss_health = boss_health;
+        }
+    }
+
+    /// Ajouter un boss de nuit (sans doublon) ; un seigneur de la nuit reconnu
+    /// devient le seigneur de l'expédition
+    pub fn add_night_boss(&mut self, boss: &str) {
+        let boss = boss.trim();
+        if boss.is_empty() {
+            return;
+        }
+        if let Some(nightlord) = nightlord_from_boss(boss) {
+            self.nightlord = Some(nightlord.to_string());
+        }
+        if !self
+            .night_bosses
+            .iter()
+            .any(|b| b.to_uppercase() == boss.to_uppercase())
+        {
+            self.night_bosses.push(boss.to_string());
+        }
+    }
+
+    pub fn finish(&mut self, outcome: RunOutcome) {
+        self.outcome = outcome;
+        self.ended_at = Some(now_secs());
+    }
+
+    pub fn get_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+    pub fn get_started_at(&self) -> u64 {
+        self.started_at
+    }
+    pub fn get_ended_at(&self) -> Option<u64> {
+        self.ended_at
+    }
+    pub fn get_nightlord(&self) -> Option<&String> {
+        self.nightlord.as_ref()
+    }
+    pub fn set_nightlord(&mut self, nightlord: Option<String>) {
+        self.nightlord = nightlord;
+    }
+    pub fn get_night_bosses(&self) -> &Vec<String> {
+        &self.night_bosses
+    }
+    pub fn get_deaths(&self) -> &Vec<RunDeath> {
+        &self.deaths
+    }
+    pub fn get_outcome(&self) -> RunOutcome {
+        self.outcome
+    }
+    pub fn is_in_progress(&self) -> bool {
+        self.outcome == RunOutcome::InProgress
+    }
+    /// Durée de l'expédition (jusqu'à maintenant si elle est en cours)
+    pub fn duration_secs(&self) -> u64 {
+        self.ended_at
+            .unwrap_or_else(now_secs)
+            .saturating_sub(self.started_at)
+    }
+}
+
+impl RunOutcome {
+    pub fn to_db_str(self) -> &'static str {
+        match self {
+            RunOutcome::InProgress => "InProgress",
+            RunOutcome::Victory => "Victory",
+            RunOutcome::Defeat => "Defeat",
+            RunOutcome::Abandoned => "Abandoned",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        match s {
+            "Victory" => RunOutcome::Victory,
+            "Defeat" => RunOutcome::Defeat,
+            "Abandoned" => RunOutcome::Abandoned,
+            _ => RunOutcome::InProgress,
+        }
+    }
+}
+
+/// Bilan des expéditions terminées contre un seigneur de la nuit
+#[derive(Debug, Clone, PartialEq)]
+pub struct NightlordSummary {
+    pub nightlord: String,
+    pub runs: u32,
+    pub victories: u32,
+    pub deaths: u32,
+}
+
+impl NightlordSummary {
+    pub fn deaths_per_run(&self) -> f32 {
+        if self.runs == 0 {
+            0.0
+        } else {
+            self.deaths as f32 / self.runs as f32
+        }
+    }
+}
+
+/// Regrouper les expéditions terminées par seigneur de la nuit (ordre de `NIGHTLORDS`,
+/// les expéditions sans seigneur connu à la fin)
+pub fn summarize_runs(runs: &[Run]) -> Vec<NightlordSummary> {
+    let mut summaries: Vec<NightlordSummary> = Vec::new();
+
+    for run in runs.iter().filter(|r| !r.is_in_progress()) {
+        let nightlord = run
+            .get_nightlord()
+            .cloned()
+            .unwrap_or_else(|| "?".to_string());
+        let summary = match summaries.iter().position(|s| s.nightlord == nightlord) {
+            Some(pos) => &mut summaries[pos],
+            None => {
+                summaries.push(NightlordSummary {
+                    nightlord,
+                    runs: 0,
+                    victories: 0,
+                    deaths: 0,
+                });
+                summaries.last_mut().unwrap()
+            }
+        };
+        summary.runs += 1;
+        summary.deaths += run.get_deaths().len() as u32;
+        if run.get_outcome() == RunOutcome::Victory {
+            summary.victories += 1;
+        }
+    }
+
+    summaries.sort_by_key(|s| {
+        NIGHTLORDS
+            .iter()
+            .position(|n| *n == s.nightlord)
+            .unwrap_or(NIGHTLORDS.len())
+    });
+    summaries
+}
+
+/// Reconnaître un seigneur de la nuit dans un nom de boss lu par l'OCR
+pub fn nightlord_from_boss(boss: &str) -> Option<&'static str> {
+    let upper = boss.to_uppercase();
+    NIGHTLORDS.iter().copied().find(|nightlord| {
+        let nightlord = nightlord.to_uppercase();
+        upper.contains(&nightlord)
+            || upper
+                .split(|c: char| !c.is_alphabetic())
+                .any(|word| normalized_levenshtein(word, &nightlord) >= 0.85)
+    })
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nightlord_is_recognized_from_ocr_boss_name() {
+        assert_eq!(
+            nightlord_from_boss("Gladius, Beast of Night"),
+            Some("Gladius")
+        );
+        assert_eq!(nightlord_from_boss("FULGHQR"), Some("Fulghor"));
+        assert_eq!(nightlord_from_boss("Tree Sentinel"), None);
+    }
+
+    #[test]
+    fn deaths_register_night_bosses_once() {
+        let mut run = Run::new();
+        run.add_death(vec!["Tree Sentinel".to_string()]);
+        run.add_death(vec!["TREE SENTINEL".to_string(), "Maris".to_string()]);
+
+        assert_eq!(run.get_deaths().len(), 2);
+        assert_eq!(run.get_night_bosses().len(), 2);
+        assert_eq!(run.get_nightlord().map(String::as_str), Some("Maris"));
+    }
+
+    #[test]
+    fn bosses_read_later_are_attached_to_the_counted_death() {
+        let mut run = Run::new();
+        run.add_death(vec![]);
+        run.add_death(vec![]);
+        run.attribute_death(0, vec!["Gladius, Beast of Night".to_string()], vec![0.25]);
+
+        assert_eq!(run.get_deaths()[0].bosses, vec!["Gladius, Beast of Night"]);
+        assert_eq!(run.get_deaths()[0].boss_health, vec![0.25]);
+        assert!(run.get_deaths()[1].bosses.is_empty());
+        assert_eq!(run.get_nightlord().map(String::as_str), Some("Gladius"));
+    }
+
+    #[test]
+    fn summary_groups_finished_runs_by_nightlord() {
+        let mut first = Run::new();
+        first.set_nightlord(Some("Maris".to_string()));
+        first.add_death(vec![]);
+        first.finish(RunOutcome::Defeat);
+
+        let mut second = Run::new();
+        second.set_nightlord(Some("Maris".to_string()));
+        second.finish(RunOutcome::Victory);
+
+        let mut third = Run::new();
+        third.set_nightlord(Some("Gladius".to_string()));
+        third.add_death(vec![]);
+
+        let mut fourth = Run::new();
+        fourth.finish(RunOutcome::Abandoned);
+
+        let summaries = summarize_runs(&[first, second, third, fourth]);
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].nightlord, "Maris");
+        assert_eq!(summaries[0].runs, 2);
+        assert_eq!(summaries[0].victories, 1);
+        assert_eq!(summaries[0].deaths_per_run(), 0.5);
+        assert_eq!(summaries[1].nightlord, "?");
+    }
+}
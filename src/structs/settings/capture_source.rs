@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::i18n::translations::{I18n, SettingsKey};
+
+pub const ALL_CAPTURE_MODES: &[CaptureMode] = &[
+    CaptureMode::Monitor,
+    CaptureMode::Window,
+    CaptureMode::ImageFolder,
+    CaptureMode::VideoFile,
+    CaptureMode::Stdin,
+];
+
+/// Type de source choisi dans les paramètres
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum CaptureMode {
+    #[default]
+    Monitor,
+    Window,
+    ImageFolder,
+    VideoFile,
+    Stdin,
+}
+
+impl fmt::Display for CaptureMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let i18n = I18n::load();
+        let text = match self {
+            CaptureMode::Monitor => i18n.settings(SettingsKey::CaptureModeMonitor),
+            CaptureMode::Window => i18n.settings(SettingsKey::CaptureModeWindow),
+            CaptureMode::ImageFolder => i18n.settings(SettingsKey::CaptureModeImageFolder),
+            CaptureMode::VideoFile => i18n.settings(SettingsKey::CaptureModeVideoFile),
+            CaptureMode::Stdin => i18n.settings(SettingsKey::CaptureModeStdin),
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+/// Source effectivement capturée par le worker OCR
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CaptureSource {
+    /// Index de l'écran dans `Monitor::all()`
+    Monitor(i8),
+    /// Titre ou nom de processus de la fenêtre du jeu (recherche insensible à la casse)
+    Window(String),
+    /// Dossier surveillé : chaque nouvelle image déposée est analysée
+    ImageFolder(PathBuf),
+    /// Fichier vidéo décodé par un sous-processus ffmpeg
+    VideoFile(PathBuf),
+    /// Images RGBA brutes de `width` x `height` reçues sur l'entrée standard
+    Stdin { width: u32, height: u32 },
+}
+
+impl CaptureSource {
+    /// Lire une source depuis la ligne de commande :
+    /// `monitor:0`, `window:ELDEN RING`, `folder:<dossier>`, `video:<fichier>`, `stdin:1920x1080`
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        let (kind, value) = arg
+            .split_once(':')
+            .ok_or(format!("Source invalide '{}' (attendu type:valeur)", arg))?;
+
+        match kind {
+            "monitor" => value
+                .parse::<i8>()
+                .map(CaptureSource::Monitor)
+                .map_err(|e| format!("Index d'écran invalide '{}': {}", value, e)),
+            "window" => Ok(CaptureSource::Window(value.to_string())),
+            "folder" => Ok(CaptureSource::ImageFolder(PathBuf::from(value))),
+            "video" => Ok(CaptureSource::VideoFile(PathBuf::from(value))),
+            "stdin" => {
+                let (width, height) = parse_frame_size(value)?;
+                Ok(CaptureSource::Stdin { width, height })
+            }
+            _ => Err(format!("Type de source inconnu '{}'", kind)),
+        }
+    }
+}
+
+/// Lire une taille d'image au format `1920x1080`
+pub fn parse_frame_size(value: &str) -> Result<(u32, u32), String> {
+    let (w, h) = value
+        .trim()
+        .split_once(['x', 'X'])
+        .ok_or(format!("Taille invalide '{}' (attendu 1920x1080)", value))?;
+    let width = w
+        .trim()
+        .parse::<u32>()
+        .map_err(|e| format!("Largeur invalide '{}': {}", w, e))?;
+    let height = h
+        .trim()
+        .parse::<u32>()
+        .map_err(|e| format!("Hauteur invalide '{}': {}", h, e))?;
+    if width == 0 || height == 0 {
+        return Err(format!("Taille nulle '{}'", value));
+    }
+    Ok((width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arg_parses_every_source() {
+        assert_eq!(
+            CaptureSource::from_arg("monitor:1").unwrap(),
+            CaptureSource::Monitor(1)
+        );
+        assert_eq!(
+            CaptureSource::from_arg("window:ELDEN RING").unwrap(),
+            CaptureSource::Window("ELDEN RING".to_string())
+        );
+        assert_eq!(
+            CaptureSource::from_arg("video:C:/vod.mp4").unwrap(),
+            CaptureSource::VideoFile(PathBuf::from("C:/vod.mp4"))
+        );
+        assert_eq!(
+            CaptureSource::from_arg("stdin:1280x720").unwrap(),
+            CaptureSource::Stdin {
+                width: 1280,
+                height: 720
+            }
+        );
+    }
+
+    #[test]
+    fn from_arg_rejects_invalid_sources() {
+        assert!(CaptureSource::from_arg("monitor").is_err());
+        assert!(CaptureSource::from_arg("webcam:0").is_err());
+        assert!(CaptureSource::from_arg("stdin:1920").is_err());
+    }
+}
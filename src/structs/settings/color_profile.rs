@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+/// Couleur du texte de mort propre à un jeu
+///
+/// Sert au pré-filtre (présence de pixels de la bonne couleur) et à la conversion
+/// en niveaux de gris envoyée à l'OCR. Valeurs entières pour rester `Hash`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ColorProfile {
+    /// Teinte minimale en degrés (0-359)
+    pub hue_min: u16,
+    /// Teinte maximale en degrés, la plage boucle si `hue_max < hue_min` (ex. 330 → 30)
+    pub hue_max: u16,
+    /// Saturation minimale (0-100 %)
+    pub saturation_min: u8,
+    /// Luminosité minimale (0-100 %)
+    pub value_min: u8,
+    /// Luminosité maximale (0-100 %)
+    pub value_max: u8,
+    /// Part minimale de pixels dans la plage, en pour mille
+    pub min_coverage: u16,
+    /// Poids R, G, B en % pour l'image envoyée à l'OCR (négatif pour soustraire un canal)
+    pub channel_weights: [i16; 3],
+    /// Écran de mort sans texte à lire : la forme du glyphe décide, sans OCR
+    #[serde(default)]
+    pub glyph_only: bool,
+}
+
+impl Default for ColorProfile {
+    fn default() -> Self {
+        Self::elden_ring()
+    }
+}
+
+impl ColorProfile {
+    /// Rouge sombre de "VOUS AVEZ PÉRI" / "YOU DIED"
+    pub fn elden_ring() -> Self {
+        Self {
+            hue_min: 330,
+            hue_max: 30,
+            saturation_min: 15,
+            value_min: 24,
+            value_max: 100,
+            min_coverage: 10,
+            channel_weights: [100, 0, 0],
+            glyph_only: false,
+        }
+    }
+
+    /// Rouge plus vif et plus saturé (Dark Souls, Dark Souls II, Bloodborne)
+    pub fn souls_red() -> Self {
+        Self {
+            hue_min: 340,
+            hue_max: 20,
+            saturation_min: 35,
+            value_min: 30,
+            value_max: 100,
+            min_coverage: 10,
+            channel_weights: [100, 0, 0],
+            glyph_only: false,
+        }
+    }
+
+    /// Idéogramme 死 rouge vif, gros caractère qui couvre une grande partie de la zone
+    pub fn sekiro() -> Self {
+        Self {
+            hue_min: 345,
+            hue_max: 15,
+            saturation_min: 50,
+            value_min: 35,
+            value_max: 100,
+            min_coverage: 30,
+            channel_weights: [120, -20, 0],
+            glyph_only: true,
+        }
+    }
+
+    /// Le pixel appartient-il à la plage de couleur du texte ?
+    pub fn matches(&self, r: u8, g: u8, b: u8) -> bool {
+        let (hue, saturation, value) = rgb_to_hsv(r, g, b);
+
+        let hue_ok = if self.hue_min <= self.hue_max {
+            (self.hue_min..=self.hue_max).contains(&hue)
+        } else {
+            hue >= self.hue_min || hue <= self.hue_max
+        };
+
+        hue_ok
+            && saturation >= self.saturation_min
+            && (self.value_min..=self.value_max).contains(&value)
+    }
+
+    /// Niveau de gris d'un pixel selon les poids de canaux
+    pub fn weighted_luma(&self, r: u8, g: u8, b: u8) -> u8 {
+        let [wr, wg, wb] = self.channel_weights;
+        let sum = r as i32 * wr as i32 + g as i32 * wg as i32 + b as i32 * wb as i32;
+        (sum / 100).clamp(0, 255) as u8
+    }
+}
+
+/// Teinte en degrés, saturation et luminosité en %
+fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let max = r.max(g).max(b) as i32;
+    let min = r.min(g).min(b) as i32;
+    let delta = max - min;
+
+    let value = (max * 100 / 255) as u8;
+    let saturation = if max == 0 {
+        0
+    } else {
+        (delta * 100 / max) as u8
+    };
+    if delta == 0 {
+        return (0, saturation, value);
+    }
+
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    let hue = if max == r {
+        60 * (g - b) / delta
+    } else if max == g {
+        60 * (b - r) / delta + 120
+    } else {
+        60 * (r - g) / delta + 240
+    };
+    (hue.rem_euclid(360) as u16, saturation, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elden_ring_profile_matches_dark_red_only() {
+        let profile = ColorProfile::elden_ring();
+
+        assert!(profile.matches(120, 20, 25));
+        assert!(profile.matches(200, 40, 90)); // teinte ~340°, la plage boucle
+        assert!(!profile.matches(30, 5, 5)); // trop sombre
+        assert!(!profile.matches(128, 128, 128)); // gris
+        assert!(!profile.matches(40, 160, 40)); // vert
+    }
+
+    #[test]
+    fn weighted_luma_clamps() {
+        let profile = ColorProfile {
+            channel_weights: [150, -50, 0],
+            ..ColorProfile::elden_ring()
+        };
+
+        assert_eq!(profile.weighted_luma(200, 0, 0), 255);
+        assert_eq!(profile.weighted_luma(10, 200, 0), 0);
+        assert_eq!(ColorProfile::elden_ring().weighted_luma(90, 200, 10), 90);
+    }
+}
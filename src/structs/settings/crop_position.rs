@@ -1,37 +1,374 @@
-use image::DynamicImage;
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, Copy)]
+/// Ratio de l'interface des jeux supportés : sur un écran 21:9 ou 32:9 l'UI reste en 16:9 au centre
+pub const UI_ASPECT_16_9: f32 = 16.0 / 9.0;
+
+/// Luminosité maximale d'un pixel de bande noire
+const BLACK_BAR_LUMA: u32 = 12;
+/// Nombre de pixels échantillonnés par ligne / colonne pour détecter les bandes
+const BLACK_BAR_SAMPLES: u32 = 32;
+/// Une bande ne peut pas dépasser cette fraction de l'image (16:9 sur 32:9 = 25 %)
+const MAX_BAR_FRACTION: f32 = 0.30;
+/// Part maximale de pixels noirs dans la zone utile : au-delà (écran de chargement, menu,
+/// scène sombre) les bandes ne se distinguent pas du contenu
+const MAX_DARK_CONTENT: f32 = 0.5;
+
+/// Zone utile de l'image en pixels (sans bandes noires)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentArea {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ContentArea {
+    pub fn full(width: u32, height: u32) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    /// Détecter les bandes noires (pillarbox / letterbox) ; elles doivent être symétriques,
+    /// sinon c'est probablement une scène sombre et on garde l'image entière.
+    /// `None` quand l'image est trop sombre pour en juger : la zone utile restante
+    /// serait en majorité noire (écran noir, chargement, scène de nuit).
+    pub fn detect<I: GenericImageView<Pixel = Rgba<u8>>>(image: &I) -> Option<Self> {
+        let (width, height) = image.dimensions();
+        if width == 0 || height == 0 {
+            return Some(Self::full(width, height));
+        }
+
+        let is_black = |x: u32, y: u32| {
+            let [r, g, b, _] = image.get_pixel(x, y).0;
+            (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000 <= BLACK_BAR_LUMA
+        };
+        let column_is_black = |x: u32| {
+            (0..BLACK_BAR_SAMPLES).all(|i| is_black(x, i * (height - 1) / (BLACK_BAR_SAMPLES - 1)))
+        };
+        let row_is_black = |y: u32| {
+            (0..BLACK_BAR_SAMPLES).all(|i| is_black(i * (width - 1) / (BLACK_BAR_SAMPLES - 1), y))
+        };
+
+        let max_x = (width as f32 * MAX_BAR_FRACTION) as u32;
+        let max_y = (height as f32 * MAX_BAR_FRACTION) as u32;
+        let left = (0..max_x).take_while(|&x| column_is_black(x)).count() as u32;
+        let right = (0..max_x)
+            .take_while(|&x| column_is_black(width - 1 - x))
+            .count() as u32;
+        let top = (0..max_y).take_while(|&y| row_is_black(y)).count() as u32;
+        let bottom = (0..max_y)
+            .take_while(|&y| row_is_black(height - 1 - y))
+            .count() as u32;
+
+        let symmetric = |a: u32, b: u32, total: u32| a.abs_diff(b) <= total / 100 + 2;
+        let (left, right) = if symmetric(left, right, width) {
+            (left, right)
+        } else {
+            (0, 0)
+        };
+        let (top, bottom) = if symmetric(top, bottom, height) {
+            (top, bottom)
+        } else {
+            (0, 0)
+        };
+
+        let area = Self {
+            x: left,
+            y: top,
+            width: width - left - right,
+            height: height - top - bottom,
+        };
+
+        let sample =
+            |i: u32, origin: u32, size: u32| origin + i * (size - 1) / (BLACK_BAR_SAMPLES - 1);
+        let dark = (0..BLACK_BAR_SAMPLES)
+            .flat_map(|i| (0..BLACK_BAR_SAMPLES).map(move |j| (i, j)))
+            .filter(|&(i, j)| {
+                is_black(
+                    sample(i, area.x, area.width),
+                    sample(j, area.y, area.height),
+                )
+            })
+            .count();
+        let samples = (BLACK_BAR_SAMPLES * BLACK_BAR_SAMPLES) as f32;
+        (dark as f32 <= samples * MAX_DARK_CONTENT).then_some(area)
+    }
+
+    /// Zone utile d'une image isolée, l'image entière si elle est trop sombre pour en juger
+    pub fn of<I: GenericImageView<Pixel = Rgba<u8>>>(image: &I) -> Self {
+        let (width, height) = image.dimensions();
+        Self::detect(image).unwrap_or_else(|| Self::full(width, height))
+    }
+
+    /// Sous-zone centrée au ratio demandé
+    pub fn with_aspect(self, aspect: f32) -> Self {
+        if self.width == 0 || self.height == 0 || aspect <= 0.0 {
+            return self;
+        }
+        let current = self.width as f32 / self.height as f32;
+        if current > aspect {
+            let width = (self.height as f32 * aspect).round() as u32;
+            Self {
+                x: self.x + (self.width - width) / 2,
+                width,
+                ..self
+            }
+        } else if current < aspect {
+            let height = (self.width as f32 / aspect).round() as u32;
+            Self {
+                y: self.y + (self.height - height) / 2,
+                height,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+/// Zone à cropper, en pourcentages (fractionnaires) de la zone de référence
+#[derive(Debug, Clone, Serialize, Deserialize, Copy)]
 pub struct CropPosition {
-    pub x_percent: u32,
-    pub y_percent: u32,
-    pub width_percent: u32,
-    pub height_percent: u32,
+    pub x_percent: f32,
+    pub y_percent: f32,
+    pub width_percent: f32,
+    pub height_percent: f32,
+    /// Ratio de l'interface sur lequel sont calculés les pourcentages ; `None` : image entière
+    /// (zones enregistrées avant la gestion du ratio, en pourcentages entiers)
+    #[serde(default)]
+    pub reference_aspect: Option<f32>,
+}
+
+impl CropPosition {
+    /// Représentation binaire des champs : même clé pour l'égalité et le hash
+    fn bits(&self) -> (u32, u32, u32, u32, Option<u32>) {
+        (
+            self.x_percent.to_bits(),
+            self.y_percent.to_bits(),
+            self.width_percent.to_bits(),
+            self.height_percent.to_bits(),
+            self.reference_aspect.map(f32::to_bits),
+        )
+    }
+}
+
+impl PartialEq for CropPosition {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for CropPosition {}
+
+impl Hash for CropPosition {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
 }
 
 impl CropPosition {
-    pub fn new(x_percent: u32, y_percent: u32, width_percent: u32, height_percent: u32) -> Self {
+    /// Zone relative à l'interface 16:9 du jeu
+    pub fn new(x_percent: f32, y_percent: f32, width_percent: f32, height_percent: f32) -> Self {
         Self {
             x_percent,
             y_percent,
             width_percent,
             height_percent,
+            reference_aspect: Some(UI_ASPECT_16_9),
         }
     }
 
-    /// Convertir en pixels et cropper l'image
-    pub fn crop_image(&self, image: &DynamicImage) -> DynamicImage {
-        let (crop_x, crop_y, crop_width, crop_height) =
-            self.to_pixels(image.width(), image.height());
+    pub fn with_reference_aspect(mut self, reference_aspect: Option<f32>) -> Self {
+        self.reference_aspect = reference_aspect;
+        self
+    }
+
+    /// Zone de référence des pourcentages dans la zone utile de l'image
+    pub fn reference_area(&self, content: ContentArea) -> ContentArea {
+        match self.reference_aspect {
+            Some(aspect) => content.with_aspect(aspect),
+            None => content,
+        }
+    }
+
+    /// Convertir en pixels et cropper l'image dans sa zone utile `content`, mesurée
+    /// une fois pour toute l'image (ou validée sur plusieurs captures)
+    pub fn crop_image(&self, image: &DynamicImage, content: ContentArea) -> DynamicImage {
+        let area = self.reference_area(content);
+        let (crop_x, crop_y, crop_width, crop_height) = self.to_pixels_in(area);
         image.crop_imm(crop_x, crop_y, crop_width, crop_height)
     }
 
-    /// Convertir en pixels réels
-    pub fn to_pixels(&self, screen_width: u32, screen_height: u32) -> (u32, u32, u32, u32) {
-        let x = (screen_width * self.x_percent) / 100;
-        let y = (screen_height * self.y_percent) / 100;
-        let width = (screen_width * self.width_percent) / 100;
-        let height = (screen_height * self.height_percent) / 100;
-        (x, y, width, height)
+    /// Cropper directement le buffer RGBA d'une capture, sans conversion en `DynamicImage`
+    pub fn crop_rgba(&self, image: &RgbaImage, content: ContentArea) -> RgbaImage {
+        let area = self.reference_area(content);
+        let (crop_x, crop_y, crop_width, crop_height) = self.to_pixels_in(area);
+        image::imageops::crop_imm(image, crop_x, crop_y, crop_width, crop_height).to_image()
+    }
+
+    /// Convertir en pixels réels (image sans bandes noires)
+    pub fn to_pixels(self, screen_width: u32, screen_height: u32) -> (u32, u32, u32, u32) {
+        self.to_pixels_in(self.reference_area(ContentArea::full(screen_width, screen_height)))
+    }
+
+    /// Convertir en pixels dans une zone de référence déjà calculée
+    pub fn to_pixels_in(self, area: ContentArea) -> (u32, u32, u32, u32) {
+        let scale =
+            |total: u32, percent: f32| ((total as f32 * percent / 100.0).round() as u32).min(total);
+        let x = scale(area.width, self.x_percent);
+        let y = scale(area.height, self.y_percent);
+        let width = scale(area.width, self.width_percent).min(area.width - x);
+        let height = scale(area.height, self.height_percent).min(area.height - y);
+        (area.x + x, area.y + y, width, height)
+    }
+
+    /// Zone à partir d'un rectangle en pixels de l'image, relative à l'interface 16:9
+    pub fn from_pixels(x: u32, y: u32, width: u32, height: u32, area: ContentArea) -> Self {
+        let percent = |value: u32, offset: u32, total: u32| {
+            let value = value.saturating_sub(offset).min(total) as f32;
+            // Précision au centième de pourcent
+            (value / total.max(1) as f32 * 10_000.0).round() / 100.0
+        };
+        let x_percent = percent(x, area.x, area.width);
+        let y_percent = percent(y, area.y, area.height);
+        let right = percent(x + width, area.x, area.width);
+        let bottom = percent(y + height, area.y, area.height);
+        Self::new(x_percent, y_percent, right - x_percent, bottom - y_percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    #[test]
+    fn ui_zones_stay_centered_on_ultrawide_monitors() {
+        let zone = CropPosition::new(25.0, 50.0, 50.0, 10.0);
+
+        // 16:9 : pas de changement
+        assert_eq!(zone.to_pixels(1920, 1080), (480, 540, 960, 108));
+        // 21:9 (2560x1080) : UI 16:9 de 1920 px centrée, 320 px de chaque côté
+        assert_eq!(zone.to_pixels(2560, 1080), (800, 540, 960, 108));
+
+        // Anciennes zones : pourcentages de l'image entière
+        let legacy = zone.with_reference_aspect(None);
+        assert_eq!(legacy.to_pixels(2560, 1080), (640, 540, 1280, 108));
+    }
+
+    #[test]
+    fn black_bars_are_detected_only_when_symmetric() {
+        // 16:9 pillarboxé dans du 21:9
+        let mut image = RgbImage::from_pixel(2560, 1080, Rgb([0, 0, 0]));
+        for x in 320..2240 {
+            for y in 0..1080 {
+                image.put_pixel(x, y, Rgb([90, 80, 70]));
+            }
+        }
+        let image = DynamicImage::ImageRgb8(image);
+        assert_eq!(
+            ContentArea::detect(&image),
+            Some(ContentArea {
+                x: 320,
+                y: 0,
+                width: 1920,
+                height: 1080
+            })
+        );
+
+        // Scène sombre à gauche seulement : pas de bandes
+        let mut dark_left = RgbImage::from_pixel(1920, 1080, Rgb([90, 80, 70]));
+        for x in 0..200 {
+            for y in 0..1080 {
+                dark_left.put_pixel(x, y, Rgb([0, 0, 0]));
+            }
+        }
+        let dark_left = DynamicImage::ImageRgb8(dark_left);
+        assert_eq!(
+            ContentArea::detect(&dark_left),
+            Some(ContentArea::full(1920, 1080))
+        );
+    }
+
+    #[test]
+    fn dark_frames_are_too_dark_to_measure() {
+        // Écran de chargement : tout noir, des « bandes » de 30 % de chaque côté
+        let black = DynamicImage::ImageRgb8(RgbImage::from_pixel(1920, 1080, Rgb([0, 0, 0])));
+        assert_eq!(ContentArea::detect(&black), None);
+        assert_eq!(ContentArea::of(&black), ContentArea::full(1920, 1080));
+
+        // Scène de nuit : seule une torche éclaire le centre
+        let mut night = RgbImage::from_pixel(1920, 1080, Rgb([6, 5, 8]));
+        for x in 860..1060 {
+            for y in 440..640 {
+                night.put_pixel(x, y, Rgb([200, 140, 60]));
+            }
+        }
+        let night = DynamicImage::ImageRgb8(night);
+        assert_eq!(ContentArea::detect(&night), None);
+        assert_eq!(ContentArea::of(&night), ContentArea::full(1920, 1080));
+    }
+
+    #[test]
+    fn raw_capture_crop_matches_dynamic_image_crop() {
+        let mut rgba = RgbaImage::from_pixel(2560, 1080, image::Rgba([0, 0, 0, 255]));
+        for x in 320..2240 {
+            for y in 0..1080 {
+                rgba.put_pixel(
+                    x,
+                    y,
+                    image::Rgba([(x % 256) as u8, (y % 256) as u8, 70, 255]),
+                );
+            }
+        }
+        let zone = CropPosition::new(31.0, 46.0, 39.0, 10.0);
+        let content = ContentArea::of(&rgba);
+        assert_eq!(content.x, 320);
+
+        let from_raw = zone.crop_rgba(&rgba, content);
+        let from_dynamic = zone
+            .crop_image(&DynamicImage::ImageRgba8(rgba), content)
+            .to_rgba8();
+        assert_eq!(from_raw.dimensions(), from_dynamic.dimensions());
+        assert_eq!(from_raw, from_dynamic);
+    }
+
+    #[test]
+    fn stored_integer_zones_are_migrated_to_full_frame_percentages() {
+        let json = r#"{"x_percent":31,"y_percent":46,"width_percent":39,"height_percent":10}"#;
+        let zone: CropPosition = serde_json::from_str(json).unwrap();
+        assert_eq!(zone.x_percent, 31.0);
+        assert_eq!(zone.reference_aspect, None);
+
+        let area = ContentArea::full(1920, 1080).with_aspect(UI_ASPECT_16_9);
+        let drawn = CropPosition::from_pixels(595, 497, 749, 108, area);
+        assert_eq!(drawn.x_percent, 30.99);
+        assert_eq!(drawn.reference_aspect, Some(UI_ASPECT_16_9));
+    }
+
+    #[test]
+    fn equality_agrees_with_hash_for_signed_zero_and_nan() {
+        use std::collections::hash_map::DefaultHasher;
+        let hash = |crop: &CropPosition| {
+            let mut hasher = DefaultHasher::new();
+            crop.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let zero = CropPosition::new(0.0, 0.1, 0.2, 0.3);
+        let negative_zero = CropPosition::new(-0.0, 0.1, 0.2, 0.3);
+        assert_ne!(zero, negative_zero);
+        assert_ne!(hash(&zero), hash(&negative_zero));
+
+        // NaN reste égal à lui-même : une zone invalide ne relance pas le worker à chaque comparaison
+        let nan = CropPosition::new(f32::NAN, 0.1, 0.2, 0.3);
+        assert_eq!(nan, nan);
+        assert_eq!(hash(&nan), hash(&nan));
     }
 }
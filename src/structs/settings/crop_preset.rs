@@ -0,0 +1,223 @@
+// crop_preset.rs - Bibliothèque de presets de zones par jeu, résolution et ratio d'écran
+//
+// Les presets sont enregistrés par l'utilisateur depuis l'éditeur de zones et se partagent
+// sous forme de fichier JSON (export / import) : chaque preset a été réglé sur un vrai écran.
+
+use super::game::{Game, GameConfig};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// Version du format de fichier de presets
+const PRESET_FILE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AspectRatio {
+    R16x9,
+    R16x10,
+    R21x9,
+    R32x9,
+}
+
+pub const ALL_ASPECT_RATIOS: &[AspectRatio] = &[
+    AspectRatio::R16x9,
+    AspectRatio::R16x10,
+    AspectRatio::R21x9,
+    AspectRatio::R32x9,
+];
+
+impl AspectRatio {
+    pub fn value(&self) -> f32 {
+        match self {
+            AspectRatio::R16x9 => 16.0 / 9.0,
+            AspectRatio::R16x10 => 16.0 / 10.0,
+            // 2560x1080 (2.37) et 3440x1440 (2.39) sont tous les deux vendus en "21:9"
+            AspectRatio::R21x9 => 64.0 / 27.0,
+            AspectRatio::R32x9 => 32.0 / 9.0,
+        }
+    }
+
+    /// Ratio le plus proche d'une résolution
+    pub fn from_resolution(width: u32, height: u32) -> Self {
+        let ratio = width as f32 / height.max(1) as f32;
+        ALL_ASPECT_RATIOS
+            .iter()
+            .copied()
+            .min_by(|a, b| {
+                (a.value() - ratio)
+                    .abs()
+                    .partial_cmp(&(b.value() - ratio).abs())
+                    .unwrap()
+            })
+            .unwrap_or(AspectRatio::R16x9)
+    }
+}
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AspectRatio::R16x9 => write!(f, "16:9"),
+            AspectRatio::R16x10 => write!(f, "16:10"),
+            AspectRatio::R21x9 => write!(f, "21:9"),
+            AspectRatio::R32x9 => write!(f, "32:9"),
+        }
+    }
+}
+
+/// Zones de détection d'un jeu pour une résolution donnée
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CropPreset {
+    pub name: String,
+    pub game: Game,
+    pub width: u32,
+    pub height: u32,
+    pub aspect: AspectRatio,
+    pub config: GameConfig,
+}
+
+impl CropPreset {
+    pub fn new(name: &str, game: Game, width: u32, height: u32, config: GameConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            game,
+            width,
+            height,
+            aspect: AspectRatio::from_resolution(width, height),
+            config,
+        }
+    }
+}
+
+impl fmt::Display for CropPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} - {}x{} ({})",
+            self.name, self.width, self.height, self.aspect
+        )
+    }
+}
+
+impl PartialEq for CropPreset {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.game == other.game
+            && self.width == other.width
+            && self.height == other.height
+    }
+}
+
+/// Fichier de presets partageable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CropPresetFile {
+    version: u32,
+    presets: Vec<CropPreset>,
+}
+
+/// Preset le plus adapté à un écran : même résolution, sinon même ratio
+pub fn suggest_preset(
+    presets: &[CropPreset],
+    game: Game,
+    width: u32,
+    height: u32,
+) -> Option<&CropPreset> {
+    let aspect = AspectRatio::from_resolution(width, height);
+    let for_game = || presets.iter().filter(move |p| p.game == game);
+
+    for_game()
+        .find(|p| p.width == width && p.height == height)
+        .or_else(|| for_game().find(|p| p.aspect == aspect))
+}
+
+pub fn export_presets(path: &Path, presets: &[CropPreset]) -> Result<(), String> {
+    let file = CropPresetFile {
+        version: PRESET_FILE_VERSION,
+        presets: presets.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| format!("Erreur écriture {}: {}", path.display(), e))
+}
+
+pub fn import_presets(path: &Path) -> Result<Vec<CropPreset>, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Erreur lecture {}: {}", path.display(), e))?;
+    let file: CropPresetFile =
+        serde_json::from_str(&json).map_err(|e| format!("Fichier de presets invalide: {}", e))?;
+    if file.version > PRESET_FILE_VERSION {
+        return Err(format!(
+            "Version de fichier de presets non supportée: {}",
+            file.version
+        ));
+    }
+    Ok(file.presets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aspect_ratio_is_matched_from_common_resolutions() {
+        assert_eq!(AspectRatio::from_resolution(1920, 1080), AspectRatio::R16x9);
+        assert_eq!(AspectRatio::from_resolution(1280, 800), AspectRatio::R16x10);
+        assert_eq!(AspectRatio::from_resolution(2560, 1080), AspectRatio::R21x9);
+        assert_eq!(AspectRatio::from_resolution(3440, 1440), AspectRatio::R21x9);
+        assert_eq!(AspectRatio::from_resolution(5120, 1440), AspectRatio::R32x9);
+    }
+
+    #[test]
+    fn suggestion_prefers_exact_resolution_then_aspect_ratio() {
+        let presets = vec![
+            CropPreset::new(
+                "Mon 16:9",
+                Game::EldenRing,
+                1920,
+                1080,
+                GameConfig::elden_ring_default(),
+            ),
+            CropPreset::new(
+                "Mon 21:9",
+                Game::EldenRing,
+                2560,
+                1080,
+                GameConfig::elden_ring_default(),
+            ),
+            CropPreset::new(
+                "UWQHD",
+                Game::Sekiro,
+                3440,
+                1440,
+                GameConfig::sekiro_default(),
+            ),
+        ];
+
+        let exact = suggest_preset(&presets, Game::EldenRing, 2560, 1080).unwrap();
+        assert_eq!(exact.name, "Mon 21:9");
+
+        let same_aspect = suggest_preset(&presets, Game::Sekiro, 3840, 1600).unwrap();
+        assert_eq!(same_aspect.name, "UWQHD");
+
+        // Aucun preset enregistré pour ce jeu et ce ratio : pas de suggestion
+        assert!(suggest_preset(&presets, Game::Sekiro, 1920, 1080).is_none());
+        assert!(suggest_preset(&presets, Game::DarkSouls3, 2560, 1080).is_none());
+    }
+
+    #[test]
+    fn presets_round_trip_through_a_file() {
+        let path = std::env::temp_dir().join("deathcompteur_presets_test.json");
+        let presets = vec![CropPreset::new(
+            "Steam Deck",
+            Game::DarkSouls3,
+            1280,
+            800,
+            GameConfig::dark_souls_3_default(),
+        )];
+
+        export_presets(&path, &presets).unwrap();
+        let imported = import_presets(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(imported, presets);
+        assert_eq!(imported[0].aspect, AspectRatio::R16x10);
+    }
+}
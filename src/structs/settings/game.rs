@@ -1,25 +1,150 @@
+use super::color_profile::ColorProfile;
 use super::crop_position::CropPosition;
+use crate::i18n::language::Language;
 use serde::{Deserialize, Serialize};
 use std::fmt;
-pub const ALL_GAMES: &[Game] = &[Game::EldenRing];
+pub const ALL_GAMES: &[Game] = &[
+    Game::EldenRing,
+    Game::Nightreign,
+    Game::DarkSouls,
+    Game::DarkSouls2,
+    Game::DarkSouls3,
+    Game::Sekiro,
+    Game::Bloodborne,
+];
+
+/// Texte de mort affiché par le jeu dans une des langues où il est sorti
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeathTextPreset {
+    pub locale: &'static str,
+    pub text: &'static str,
+}
+
+const fn preset(locale: &'static str, text: &'static str) -> DeathTextPreset {
+    DeathTextPreset { locale, text }
+}
+
+const ELDEN_RING_DEATH_TEXTS: &[DeathTextPreset] = &[
+    preset("EN", "YOU DIED"),
+    preset("FR", "VOUS AVEZ PERI"),
+    preset("ES", "HAS MUERTO"),
+    preset("DE", "SIE SIND GESTORBEN"),
+    preset("IT", "SEI MORTO"),
+    preset("PT", "VOCE MORREU"),
+    preset("PL", "NIE ZYJESZ"),
+];
+
+const SOULS_DEATH_TEXTS: &[DeathTextPreset] = &[
+    preset("EN", "YOU DIED"),
+    preset("FR", "VOUS ETES MORT"),
+    preset("ES", "HAS MUERTO"),
+    preset("DE", "SIE SIND GESTORBEN"),
+    preset("IT", "SEI MORTO"),
+    preset("PT", "VOCE MORREU"),
+    preset("PL", "NIE ZYJESZ"),
+];
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, Hash)]
 pub enum Game {
     EldenRing,
+    Nightreign,
+    DarkSouls,
+    DarkSouls2,
+    DarkSouls3,
+    Sekiro,
+    Bloodborne,
 }
 impl PartialEq for Game {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Game::EldenRing, Game::EldenRing) => true,
-        }
+        matches!(
+            (self, other),
+            (Game::EldenRing, Game::EldenRing)
+                | (Game::Nightreign, Game::Nightreign)
+                | (Game::DarkSouls, Game::DarkSouls)
+                | (Game::DarkSouls2, Game::DarkSouls2)
+                | (Game::DarkSouls3, Game::DarkSouls3)
+                | (Game::Sekiro, Game::Sekiro)
+                | (Game::Bloodborne, Game::Bloodborne)
+        )
     }
 }
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Game::EldenRing => write!(f, "Elden Ring"),
+            Game::Nightreign => write!(f, "Elden Ring Nightreign"),
+            Game::DarkSouls => write!(f, "Dark Souls"),
+            Game::DarkSouls2 => write!(f, "Dark Souls II"),
+            Game::DarkSouls3 => write!(f, "Dark Souls III"),
+            Game::Sekiro => write!(f, "Sekiro"),
+            Game::Bloodborne => write!(f, "Bloodborne"),
+        }
+    }
+}
+
+impl Game {
+    /// Texte affiché à la mort, sans accents (l'OCR les normalise) ; aucun pour Sekiro,
+    /// dont l'écran de mort est l'idéogramme 死 reconnu par sa forme
+    pub fn default_death_text(&self, language: &Language) -> &'static str {
+        match (self, language) {
+            (Game::EldenRing | Game::Nightreign, Language::French) => "VOUS AVEZ PERI",
+            (Game::Sekiro, _) => "",
+            (_, Language::French) => "VOUS ETES MORT",
+            (_, Language::English) => "YOU DIED",
+        }
+    }
+
+    /// Textes de mort de toutes les langues du jeu, sans accents
+    pub fn death_text_presets(&self) -> &'static [DeathTextPreset] {
+        match self {
+            Game::EldenRing | Game::Nightreign => ELDEN_RING_DEATH_TEXTS,
+            Game::Sekiro => &[],
+            Game::DarkSouls | Game::DarkSouls2 | Game::DarkSouls3 | Game::Bloodborne => {
+                SOULS_DEATH_TEXTS
+            }
+        }
+    }
+
+    /// Titre de la fenêtre du jeu (capture par fenêtre)
+    pub fn window_title(&self) -> &'static str {
+        match self {
+            Game::EldenRing => "ELDEN RING",
+            Game::Nightreign => "NIGHTREIGN",
+            Game::DarkSouls => "DARK SOULS",
+            Game::DarkSouls2 => "DARK SOULS II",
+            Game::DarkSouls3 => "DARK SOULS III",
+            Game::Sekiro => "Sekiro",
+            Game::Bloodborne => "Bloodborne",
+        }
+    }
+
+    /// Exécutables du jeu (Windows, Proton/Wine), pour la détection automatique
+    pub fn process_names(&self) -> &'static [&'static str] {
+        match self {
+            Game::EldenRing => &["eldenring.exe"],
+            Game::Nightreign => &["nightreign.exe"],
+            Game::DarkSouls => &["DarkSoulsRemastered.exe", "DARKSOULS.exe"],
+            Game::DarkSouls2 => &["DarkSoulsII.exe"],
+            Game::DarkSouls3 => &["DarkSoulsIII.exe"],
+            Game::Sekiro => &["sekiro.exe"],
+            // Émulateur PS4 : le jeu lancé est reconnu par son identifiant (`title_ids`)
+            Game::Bloodborne => &["shadPS4.exe", "shadps4"],
+        }
+    }
+
+    /// Identifiants PS4 (régions, édition GOTY) exigés dans la ligne de commande ou le titre
+    /// de fenêtre quand le processus est un émulateur qui peut lancer n'importe quel jeu
+    pub fn title_ids(&self) -> &'static [&'static str] {
+        match self {
+            Game::Bloodborne => &["CUSA00900", "CUSA00207", "CUSA03173", "CUSA03023"],
+            _ => &[],
         }
     }
+
+    /// Jeu organisé en expéditions (une session par run) plutôt qu'en monde persistant
+    pub fn is_run_based(&self) -> bool {
+        matches!(self, Game::Nightreign)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
@@ -29,17 +154,83 @@ pub struct GameConfig {
 
     /// Zones pour détecter les noms de boss (peut y en avoir plusieurs)
     boss_zones: Vec<CropPosition>,
+
+    /// Couleur du texte de mort (pré-filtre et extraction de canal)
+    #[serde(default)]
+    color_profile: ColorProfile,
 }
 
 impl GameConfig {
     pub fn elden_ring_default() -> Self {
         Self {
-            death_zone: CropPosition::new(31, 46, 39, 10),
+            death_zone: CropPosition::new(31.0, 46.0, 39.0, 10.0),
             boss_zones: vec![
-                CropPosition::new(24, 77, 53, 5),
-                CropPosition::new(24, 72, 53, 5),
-                CropPosition::new(24, 67, 53, 5),
+                CropPosition::new(24.0, 77.0, 53.0, 5.0),
+                CropPosition::new(24.0, 72.0, 53.0, 5.0),
+                CropPosition::new(24.0, 67.0, 53.0, 5.0),
             ],
+            color_profile: ColorProfile::elden_ring(),
+        }
+    }
+
+    /// Nightreign : mêmes écrans de mort et barres de boss qu'Elden Ring
+    pub fn nightreign_default() -> Self {
+        Self::elden_ring_default()
+    }
+
+    /// Dark Souls Remastered : barres de boss en bas, empilées vers le haut
+    pub fn dark_souls_default() -> Self {
+        Self {
+            death_zone: CropPosition::new(30.0, 44.0, 40.0, 12.0),
+            boss_zones: vec![
+                CropPosition::new(20.0, 82.0, 50.0, 4.0),
+                CropPosition::new(20.0, 76.0, 50.0, 4.0),
+            ],
+            color_profile: ColorProfile::souls_red(),
+        }
+    }
+
+    pub fn dark_souls_2_default() -> Self {
+        Self {
+            death_zone: CropPosition::new(30.0, 44.0, 40.0, 12.0),
+            boss_zones: vec![
+                CropPosition::new(22.0, 80.0, 50.0, 4.0),
+                CropPosition::new(22.0, 75.0, 50.0, 4.0),
+                CropPosition::new(22.0, 70.0, 50.0, 4.0),
+            ],
+            color_profile: ColorProfile::souls_red(),
+        }
+    }
+
+    pub fn dark_souls_3_default() -> Self {
+        Self {
+            death_zone: CropPosition::new(31.0, 45.0, 38.0, 10.0),
+            boss_zones: vec![
+                CropPosition::new(24.0, 79.0, 52.0, 4.0),
+                CropPosition::new(24.0, 74.0, 52.0, 4.0),
+                CropPosition::new(24.0, 69.0, 52.0, 4.0),
+            ],
+            color_profile: ColorProfile::elden_ring(),
+        }
+    }
+
+    /// Sekiro : idéogramme 死 rouge au centre, barre du boss en haut à gauche
+    pub fn sekiro_default() -> Self {
+        Self {
+            death_zone: CropPosition::new(35.0, 30.0, 30.0, 40.0),
+            boss_zones: vec![CropPosition::new(3.0, 5.0, 25.0, 4.0)],
+            color_profile: ColorProfile::sekiro(),
+        }
+    }
+
+    pub fn bloodborne_default() -> Self {
+        Self {
+            death_zone: CropPosition::new(30.0, 44.0, 40.0, 12.0),
+            boss_zones: vec![
+                CropPosition::new(18.0, 84.0, 40.0, 4.0),
+                CropPosition::new(18.0, 79.0, 40.0, 4.0),
+            ],
+            color_profile: ColorProfile::souls_red(),
         }
     }
 
@@ -49,4 +240,13 @@ impl GameConfig {
     pub fn get_boss_zones(&self) -> &Vec<CropPosition> {
         &self.boss_zones
     }
+    pub fn get_color_profile(&self) -> &ColorProfile {
+        &self.color_profile
+    }
+    pub fn set_death_zone(&mut self, death_zone: CropPosition) {
+        self.death_zone = death_zone;
+    }
+    pub fn set_boss_zones(&mut self, boss_zones: Vec<CropPosition>) {
+        self.boss_zones = boss_zones;
+    }
 }
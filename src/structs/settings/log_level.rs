@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::i18n::translations::{I18n, SettingsKey};
+
+pub const ALL_LOG_LEVELS: &[LogLevel] = &[
+    LogLevel::Error,
+    LogLevel::Warn,
+    LogLevel::Info,
+    LogLevel::Debug,
+    LogLevel::Trace,
+];
+
+/// Niveau de détail du journal, modifiable sans redémarrer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    /// Inclut les durées de chaque étape de l'OCR
+    Trace,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let i18n = I18n::load();
+        let text = match self {
+            LogLevel::Error => i18n.settings(SettingsKey::LogLevelError),
+            LogLevel::Warn => i18n.settings(SettingsKey::LogLevelWarn),
+            LogLevel::Info => i18n.settings(SettingsKey::LogLevelInfo),
+            LogLevel::Debug => i18n.settings(SettingsKey::LogLevelDebug),
+            LogLevel::Trace => i18n.settings(SettingsKey::LogLevelTrace),
+        };
+
+        write!(f, "{text}")
+    }
+}
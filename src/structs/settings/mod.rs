@@ -1,4 +1,8 @@
+pub mod capture_source;
+pub mod color_profile;
 pub mod crop_position;
+pub mod crop_preset;
 pub mod game;
+pub mod log_level;
 pub mod screen;
 pub mod settings;
@@ -1,5 +1,5 @@
 use std::fmt;
-use xcap::Monitor;
+use xcap::{Monitor, Window};
 
 pub fn get_screens_vec() -> Result<Vec<ScreenInfo>, String> {
     let monitors = Monitor::all().map_err(|e| format!("Erreur Monitor::all: {}", e))?;
@@ -13,15 +13,42 @@ pub fn get_screens_vec() -> Result<Vec<ScreenInfo>, String> {
             .name()
             .map_err(|e| format!("Erreur monitor.name() pour écran {}: {}", index, e))?;
 
-        result.push(ScreenInfo { index, name });
+        // Résolution utilisée pour suggérer un preset de zones
+        let width = monitor.width().unwrap_or(0);
+        let height = monitor.height().unwrap_or(0);
+
+        result.push(ScreenInfo {
+            index,
+            name,
+            width,
+            height,
+        });
     }
 
     Ok(result)
 }
+
+/// Titres des fenêtres visibles, pour choisir la fenêtre du jeu
+pub fn get_windows_vec() -> Result<Vec<String>, String> {
+    let windows = Window::all().map_err(|e| format!("Erreur Window::all: {}", e))?;
+
+    let mut result: Vec<String> = windows
+        .into_iter()
+        .filter(|w| !w.is_minimized().unwrap_or(false))
+        .filter_map(|w| w.title().ok())
+        .filter(|title| !title.trim().is_empty())
+        .collect();
+    result.sort();
+    result.dedup();
+
+    Ok(result)
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScreenInfo {
     pub index: i8,
     pub name: String,
+    pub width: u32,
+    pub height: u32,
 }
 
 impl fmt::Display for ScreenInfo {
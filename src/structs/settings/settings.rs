@@ -1,6 +1,13 @@
 // src/structs/settings/settings.rs
+use super::capture_source::{CaptureMode, CaptureSource, parse_frame_size};
+use super::crop_preset::CropPreset;
 use super::game::{Game, GameConfig};
-use crate::{i18n::language::Language, structs::storage::Storage};
+use super::log_level::LogLevel;
+use crate::structs::encounter::{Encounter, builtin_encounters};
+use crate::{
+    i18n::language::{ALL_LANGUAGES, Language},
+    structs::storage::Storage,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -11,6 +18,44 @@ pub struct Settings {
     language: Language,
     death_text: String,
 
+    /// Capture d'un écran entier ou d'une fenêtre précise
+    #[serde(default)]
+    capture_mode: CaptureMode,
+    /// Titre ou nom de processus de la fenêtre du jeu
+    #[serde(default)]
+    window_name: String,
+    /// Dossier d'images ou fichier vidéo à analyser
+    #[serde(default)]
+    source_path: String,
+    /// Taille des images brutes lues sur stdin (ex: 1920x1080)
+    #[serde(default = "default_stdin_frame_size")]
+    stdin_frame_size: String,
+
+    /// Choisir le jeu automatiquement à partir des processus en cours (désactivé par défaut)
+    #[serde(default)]
+    auto_detect_game: bool,
+    /// Démarrer / arrêter l'OCR quand le jeu est lancé / fermé
+    #[serde(default)]
+    auto_start_ocr: bool,
+    /// Part d'un cœur (en %) que le scan OCR peut occuper
+    #[serde(default = "default_cpu_budget")]
+    cpu_budget: u8,
+    /// Niveau de détail du journal
+    #[serde(default)]
+    log_level: LogLevel,
+
+    /// Rencontres à plusieurs boss définies par l'utilisateur
+    #[serde(default)]
+    encounters: Vec<Encounter>,
+
+    /// Textes de mort prédéfinis acceptés, par jeu (tous tant que rien n'est choisi)
+    #[serde(default)]
+    death_texts: HashMap<Game, Vec<String>>,
+
+    /// Presets de zones créés ou importés par l'utilisateur
+    #[serde(default)]
+    crop_presets: Vec<CropPreset>,
+
     /// Configurations personnalisées par jeu
     #[serde(default)]
     pub custom_game_configs: HashMap<Game, GameConfig>,
@@ -32,22 +77,103 @@ impl Settings {
     fn default_game_config(game: &Game) -> GameConfig {
         match game {
             Game::EldenRing => GameConfig::elden_ring_default(),
-            // Game::DarkSouls3 => GameConfig::dark_souls_3_default(),
+            Game::Nightreign => GameConfig::nightreign_default(),
+            Game::DarkSouls => GameConfig::dark_souls_default(),
+            Game::DarkSouls2 => GameConfig::dark_souls_2_default(),
+            Game::DarkSouls3 => GameConfig::dark_souls_3_default(),
+            Game::Sekiro => GameConfig::sekiro_default(),
+            Game::Bloodborne => GameConfig::bloodborne_default(),
         }
     }
 
     /// Définir une configuration personnalisée
-    // pub fn set_custom_game_config(&mut self, game: Game, config: GameConfig) {
-    //     self.custom_game_configs.insert(game, config);
-    // }
+    pub fn set_custom_game_config(&mut self, game: Game, config: GameConfig) {
+        self.custom_game_configs.insert(game, config);
+    }
+
+    /// Revenir aux zones par défaut du jeu
+    pub fn remove_custom_game_config(&mut self, game: &Game) {
+        self.custom_game_configs.remove(game);
+    }
+
+    pub fn get_default_game_config(&self) -> GameConfig {
+        Self::default_game_config(&self.game)
+    }
+
+    /// Presets enregistrés ou importés par l'utilisateur
+    pub fn get_crop_presets(&self) -> &Vec<CropPreset> {
+        &self.crop_presets
+    }
+    /// Ajouter un preset, en remplaçant celui du même nom pour le même jeu et la même résolution
+    pub fn add_crop_preset(&mut self, preset: CropPreset) {
+        self.crop_presets.retain(|p| *p != preset);
+        self.crop_presets.push(preset);
+    }
 
     pub fn set_game(&mut self, game: Game) {
         self.game = game;
     }
+
+    /// Changer de jeu en reprenant son texte de mort et son titre de fenêtre,
+    /// sauf si l'utilisateur les a personnalisés
+    pub fn switch_game(&mut self, game: Game) {
+        let previous = self.game;
+        if ALL_LANGUAGES
+            .iter()
+            .any(|l| self.death_text == previous.default_death_text(l))
+        {
+            self.death_text = game.default_death_text(&self.language).to_string();
+        }
+        if self.window_name == previous.window_title() {
+            self.window_name = game.window_title().to_string();
+        }
+        self.set_game(game);
+    }
     pub fn get_game(&self) -> Game {
         self.game
     }
 
+    pub fn set_auto_detect_game(&mut self, auto_detect_game: bool) {
+        self.auto_detect_game = auto_detect_game;
+    }
+    pub fn get_auto_detect_game(&self) -> bool {
+        self.auto_detect_game
+    }
+
+    pub fn set_auto_start_ocr(&mut self, auto_start_ocr: bool) {
+        self.auto_start_ocr = auto_start_ocr;
+    }
+    pub fn get_auto_start_ocr(&self) -> bool {
+        self.auto_start_ocr
+    }
+
+    pub fn set_cpu_budget(&mut self, cpu_budget: u8) {
+        self.cpu_budget = cpu_budget;
+    }
+    pub fn get_cpu_budget(&self) -> u8 {
+        self.cpu_budget
+    }
+
+    pub fn set_log_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+    pub fn get_log_level(&self) -> LogLevel {
+        self.log_level
+    }
+
+    /// Rencontres de l'utilisateur (prioritaires) puis rencontres connues
+    pub fn get_encounters(&self) -> Vec<Encounter> {
+        self.encounters
+            .iter()
+            .cloned()
+            .chain(builtin_encounters())
+            .collect()
+    }
+    pub fn add_encounter(&mut self, encounter: Encounter) {
+        self.encounters.retain(|e| e.name != encounter.name);
+        self.encounters.push(encounter);
+    }
+
     pub fn set_screen(&mut self, screen: i8) {
         self.screen = screen;
     }
@@ -55,6 +181,49 @@ impl Settings {
         self.screen
     }
 
+    pub fn set_capture_mode(&mut self, capture_mode: CaptureMode) {
+        self.capture_mode = capture_mode;
+    }
+    pub fn get_capture_mode(&self) -> CaptureMode {
+        self.capture_mode
+    }
+
+    pub fn set_window_name(&mut self, window_name: String) {
+        self.window_name = window_name;
+    }
+    pub fn get_window_name(&self) -> &String {
+        &self.window_name
+    }
+
+    pub fn set_source_path(&mut self, source_path: String) {
+        self.source_path = source_path;
+    }
+    pub fn get_source_path(&self) -> &String {
+        &self.source_path
+    }
+
+    pub fn set_stdin_frame_size(&mut self, stdin_frame_size: String) {
+        self.stdin_frame_size = stdin_frame_size;
+    }
+    pub fn get_stdin_frame_size(&self) -> &String {
+        &self.stdin_frame_size
+    }
+
+    /// Source de capture à utiliser par le worker OCR
+    pub fn get_capture_source(&self) -> CaptureSource {
+        match self.capture_mode {
+            CaptureMode::Monitor => CaptureSource::Monitor(self.screen),
+            CaptureMode::Window => CaptureSource::Window(self.window_name.clone()),
+            CaptureMode::ImageFolder => CaptureSource::ImageFolder(self.source_path.clone().into()),
+            CaptureMode::VideoFile => CaptureSource::VideoFile(self.source_path.clone().into()),
+            CaptureMode::Stdin => {
+                let (width, height) =
+                    parse_frame_size(&self.stdin_frame_size).unwrap_or((1920, 1080));
+                CaptureSource::Stdin { width, height }
+            }
+        }
+    }
+
     pub fn get_language(&self) -> &Language {
         &self.language
     }
@@ -67,6 +236,51 @@ impl Settings {
     pub fn get_death_text(&self) -> &String {
         &self.death_text
     }
+
+    /// Textes de mort prédéfinis acceptés pour le jeu actuel : par défaut celui de la
+    /// langue de l'interface, les autres langues s'ajoutent à la main
+    fn selected_death_texts(&self) -> Vec<String> {
+        match self.death_texts.get(&self.game) {
+            Some(selected) => selected.clone(),
+            None => self
+                .game
+                .death_text_presets()
+                .iter()
+                .filter(|p| p.locale == self.language.locale())
+                .map(|p| p.text.to_string())
+                .collect(),
+        }
+    }
+
+    /// Tous les textes reconnus comme une mort : le texte personnalisé puis les presets choisis
+    pub fn get_death_texts(&self) -> Vec<String> {
+        let mut texts = Vec::new();
+        let custom = self.death_text.trim().to_uppercase();
+        if !custom.is_empty() {
+            texts.push(custom);
+        }
+        for text in self.selected_death_texts() {
+            if !texts.contains(&text) {
+                texts.push(text);
+            }
+        }
+        texts
+    }
+
+    pub fn is_death_text_preset_selected(&self, text: &str) -> bool {
+        self.selected_death_texts().iter().any(|t| t == text)
+    }
+
+    /// Accepter / ne plus accepter un texte prédéfini pour le jeu actuel
+    pub fn toggle_death_text_preset(&mut self, text: &str) {
+        let mut selected = self.selected_death_texts();
+        if let Some(pos) = selected.iter().position(|t| t == text) {
+            selected.remove(pos);
+        } else {
+            selected.push(text.to_string());
+        }
+        self.death_texts.insert(self.game, selected);
+    }
     pub fn load() -> Self {
         Storage::load_settings().unwrap_or_default()
     }
@@ -83,6 +297,79 @@ impl Default for Settings {
             language: Language::French,
             custom_game_configs: HashMap::new(),
             death_text: String::from("VOUS AVEZ PERI"),
+            death_texts: HashMap::new(),
+            capture_mode: CaptureMode::Monitor,
+            window_name: String::from("ELDEN RING"),
+            source_path: String::new(),
+            stdin_frame_size: default_stdin_frame_size(),
+            auto_detect_game: false,
+            auto_start_ocr: false,
+            cpu_budget: default_cpu_budget(),
+            log_level: LogLevel::default(),
+            encounters: Vec::new(),
+            crop_presets: Vec::new(),
         }
     }
 }
+
+fn default_stdin_frame_size() -> String {
+    String::from("1920x1080")
+}
+
+fn default_cpu_budget() -> u8 {
+    25
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switch_game_keeps_custom_death_text() {
+        let mut settings = Settings::default();
+        settings.switch_game(Game::DarkSouls3);
+        assert_eq!(settings.get_death_text(), "VOUS ETES MORT");
+        assert_eq!(settings.get_window_name(), "DARK SOULS III");
+
+        settings.set_death_text("TU ES MORT".to_string());
+        settings.switch_game(Game::Sekiro);
+        assert_eq!(settings.get_death_text(), "TU ES MORT");
+        assert_eq!(settings.get_window_name(), "Sekiro");
+    }
+
+    #[test]
+    fn death_texts_default_to_the_interface_language() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.get_death_texts(), vec!["VOUS AVEZ PERI"]);
+        assert!(!settings.is_death_text_preset_selected("YOU DIED"));
+
+        settings.toggle_death_text_preset("HAS MUERTO");
+        assert!(settings.is_death_text_preset_selected("HAS MUERTO"));
+        assert_eq!(
+            settings.get_death_texts(),
+            vec!["VOUS AVEZ PERI", "HAS MUERTO"]
+        );
+
+        // Le choix est propre à chaque jeu
+        settings.switch_game(Game::DarkSouls3);
+        assert!(!settings.is_death_text_preset_selected("HAS MUERTO"));
+        settings.set_language(Language::English);
+        assert!(settings.is_death_text_preset_selected("YOU DIED"));
+
+        // Sekiro n'a pas de texte de mort : détection par la forme seule
+        settings.switch_game(Game::Sekiro);
+        assert!(settings.get_death_texts().is_empty());
+        assert!(settings.get_game_config().get_color_profile().glyph_only);
+    }
+
+    #[test]
+    fn game_auto_detection_is_opt_in() {
+        assert!(!Settings::default().get_auto_detect_game());
+
+        // Paramètres enregistrés avant l'option
+        let mut saved = serde_json::to_value(Settings::default()).unwrap();
+        saved.as_object_mut().unwrap().remove("auto_detect_game");
+        let loaded: Settings = serde_json::from_value(saved).unwrap();
+        assert!(!loaded.get_auto_detect_game());
+    }
+}
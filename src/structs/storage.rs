@@ -1,23 +1,30 @@
 use crate::structs::recorder::RecorderType;
 
 use super::recorder::Recorder;
+use super::run::{Run, RunOutcome};
 use super::settings::settings::Settings;
 use directories::ProjectDirs;
 use rusqlite::{Connection, Result as SqlResult};
 use std::{collections::HashSet, path::PathBuf};
+use tracing::instrument;
 
 pub struct Storage;
 
 impl Storage {
-    // Obtenir le chemin de la base de données
-    fn get_db_path() -> Result<PathBuf, String> {
+    // Obtenir le répertoire de données de l'application (créé si besoin)
+    pub fn get_data_dir() -> Result<PathBuf, String> {
         let proj_dirs = ProjectDirs::from("", "", "DeathCompteur")
             .ok_or_else(|| "Impossible de déterminer le répertoire de données".to_string())?;
 
         let data_dir = proj_dirs.data_dir();
         std::fs::create_dir_all(data_dir).map_err(|e| e.to_string())?;
 
-        Ok(data_dir.join("deathcompteur.db"))
+        Ok(data_dir.to_path_buf())
+    }
+
+    // Obtenir le chemin de la base de données
+    fn get_db_path() -> Result<PathBuf, String> {
+        Ok(Self::get_data_dir()?.join("deathcompteur.db"))
     }
 
     // Ouvrir la connexion et initialiser les tables
@@ -38,7 +45,19 @@ impl Storage {
                 counter         INTEGER NOT NULL DEFAULT 0,
                 is_active       INTEGER NOT NULL DEFAULT 0,
                 position        INTEGER NOT NULL DEFAULT 0,
-                recorder_type   TEXT NOT NULL DEFAULT 'Classic'
+                recorder_type   TEXT NOT NULL DEFAULT 'Classic',
+                boss_id         TEXT,
+                attempts        TEXT NOT NULL DEFAULT '[]'
+            );
+
+            CREATE TABLE IF NOT EXISTS runs (
+                uuid            TEXT PRIMARY KEY,
+                started_at      INTEGER NOT NULL,
+                ended_at        INTEGER,
+                nightlord       TEXT,
+                night_bosses    TEXT NOT NULL DEFAULT '[]',
+                deaths          TEXT NOT NULL DEFAULT '[]',
+                outcome         TEXT NOT NULL DEFAULT 'InProgress'
             );
 
             CREATE TABLE IF NOT EXISTS settings (
@@ -53,6 +72,11 @@ impl Storage {
         // let _ = conn.execute_batch(
         //     "ALTER TABLE recorders ADD COLUMN recorder_type TEXT NOT NULL DEFAULT 'Classic';"
         // );
+        // Identifiant canonique du boss (échoue sans conséquence si la colonne existe déjà)
+        let _ = conn.execute_batch("ALTER TABLE recorders ADD COLUMN boss_id TEXT;");
+        // PV restants du boss à chaque tentative (JSON)
+        let _ = conn
+            .execute_batch("ALTER TABLE recorders ADD COLUMN attempts TEXT NOT NULL DEFAULT '[]';");
 
         Ok(())
     }
@@ -61,6 +85,12 @@ impl Storage {
     // Recorders
     // -------------------------
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        err,
+        fields(classic = classic_recorders.len(), global = global_recorders.len())
+    )]
     pub fn save_all_recorders(
         classic_recorders: &Vec<Recorder>,
         global_recorders: &Vec<Recorder>,
@@ -98,21 +128,25 @@ impl Storage {
 
         for recorder in classic_recorders.iter() {
             tx.execute(
-                "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type, boss_id, attempts)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
                  ON CONFLICT(uuid) DO UPDATE SET
                     title = excluded.title,
                     counter = excluded.counter,
                     is_active = excluded.is_active,
                     position = excluded.position,
-                    recorder_type = excluded.recorder_type",
+                    recorder_type = excluded.recorder_type,
+                    boss_id = excluded.boss_id,
+                    attempts = excluded.attempts",
                 rusqlite::params![
                     recorder.get_uuid().to_string(),
                     recorder.get_title(),
                     recorder.get_counter(),
                     recorder.get_status_recorder() as i32,
                     position,
-                    recorder.get_type().to_db_str()
+                    recorder.get_type().to_db_str(),
+                    recorder.get_boss_id(),
+                    serde_json::to_string(recorder.get_attempts()).map_err(|e| e.to_string())?
                 ],
             )
             .map_err(|e| e.to_string())?;
@@ -122,21 +156,25 @@ impl Storage {
 
         for recorder in global_recorders.iter() {
             tx.execute(
-                "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type, boss_id, attempts)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
                  ON CONFLICT(uuid) DO UPDATE SET
                     title = excluded.title,
                     counter = excluded.counter,
                     is_active = excluded.is_active,
                     position = excluded.position,
-                    recorder_type = excluded.recorder_type",
+                    recorder_type = excluded.recorder_type,
+                    boss_id = excluded.boss_id,
+                    attempts = excluded.attempts",
                 rusqlite::params![
                     recorder.get_uuid().to_string(),
                     recorder.get_title(),
                     recorder.get_counter(),
                     recorder.get_status_recorder() as i32,
                     position,
-                    recorder.get_type().to_db_str()
+                    recorder.get_type().to_db_str(),
+                    recorder.get_boss_id(),
+                    serde_json::to_string(recorder.get_attempts()).map_err(|e| e.to_string())?
                 ],
             )
             .map_err(|e| e.to_string())?;
@@ -148,11 +186,12 @@ impl Storage {
         Ok(())
     }
 
+    #[instrument(level = "debug", err)]
     pub fn load_recorders() -> Result<(Vec<Recorder>, Vec<Recorder>), String> {
         let conn = Self::open()?;
 
         let mut stmt = conn
-            .prepare("SELECT uuid, title, counter, is_active, recorder_type FROM recorders ORDER BY position ASC")
+            .prepare("SELECT uuid, title, counter, is_active, recorder_type, boss_id, attempts FROM recorders ORDER BY position ASC")
             .map_err(|e| e.to_string())?;
 
         let all_recorders: Vec<Recorder> = stmt
@@ -162,19 +201,33 @@ impl Storage {
                 let counter: u32 = row.get(2)?;
                 let is_active: i32 = row.get(3)?;
                 let recorder_type: String = row.get(4)?;
-                Ok((uuid_str, title, counter, is_active, recorder_type))
-            })
-            .map_err(|e| e.to_string())?
-            .filter_map(|r| r.ok())
-            .map(|(uuid_str, title, counter, is_active, recorder_type)| {
-                Recorder::from_db(
+                let boss_id: Option<String> = row.get(5)?;
+                let attempts: String = row.get(6)?;
+                Ok((
                     uuid_str,
                     title,
                     counter,
-                    is_active != 0,
-                    RecorderType::from_db_str(&recorder_type),
-                )
+                    is_active,
+                    recorder_type,
+                    boss_id,
+                    attempts,
+                ))
             })
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .map(
+                |(uuid_str, title, counter, is_active, recorder_type, boss_id, attempts)| {
+                    Recorder::from_db(
+                        uuid_str,
+                        title,
+                        counter,
+                        is_active != 0,
+                        RecorderType::from_db_str(&recorder_type),
+                        boss_id,
+                        serde_json::from_str(&attempts).unwrap_or_default(),
+                    )
+                },
+            )
             .collect();
 
         // Séparer en deux Vec : classics et globals
@@ -192,6 +245,7 @@ impl Storage {
         Ok((classic_recorders, global_recorders))
     }
 
+    #[instrument(level = "debug", skip_all, err)]
     pub fn insert_recorder_at_first_position(recorder: &Recorder) -> Result<(), String> {
         let mut conn = Self::open().map_err(|e| e.to_string())?;
         let tx = conn.transaction().map_err(|e| e.to_string())?;
@@ -202,20 +256,24 @@ impl Storage {
 
         // 2️⃣ Insérer le nouveau recorder en position 0
         tx.execute(
-            "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type)
-             VALUES (?1, ?2, ?3, ?4, 0, ?5)
+            "INSERT INTO recorders (uuid, title, counter, is_active, position, recorder_type, boss_id, attempts)
+             VALUES (?1, ?2, ?3, ?4, 0, ?5, ?6, ?7)
              ON CONFLICT(uuid) DO UPDATE SET
                 title = excluded.title,
                 counter = excluded.counter,
                 is_active = excluded.is_active,
                 position = 0,
-                recorder_type = excluded.recorder_type",
+                recorder_type = excluded.recorder_type,
+                boss_id = excluded.boss_id,
+                attempts = excluded.attempts",
             rusqlite::params![
                 recorder.get_uuid().to_string(),
                 recorder.get_title(),
                 recorder.get_counter(),
                 recorder.get_status_recorder() as i32,
-                recorder.get_type().clone().to_db_str()
+                recorder.get_type().clone().to_db_str(),
+                recorder.get_boss_id(),
+                serde_json::to_string(recorder.get_attempts()).map_err(|e| e.to_string())?
             ],
         )
         .map_err(|e| e.to_string())?;
@@ -226,10 +284,97 @@ impl Storage {
         Ok(())
     }
 
+    // -------------------------
+    // Expéditions (Nightreign)
+    // -------------------------
+
+    #[instrument(level = "debug", skip_all, err)]
+    pub fn save_run(run: &Run) -> Result<(), String> {
+        let conn = Self::open()?;
+        let night_bosses =
+            serde_json::to_string(run.get_night_bosses()).map_err(|e| e.to_string())?;
+        let deaths = serde_json::to_string(run.get_deaths()).map_err(|e| e.to_string())?;
+
+        conn.execute(
+            "INSERT INTO runs (uuid, started_at, ended_at, nightlord, night_bosses, deaths, outcome)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(uuid) DO UPDATE SET
+                ended_at = excluded.ended_at,
+                nightlord = excluded.nightlord,
+                night_bosses = excluded.night_bosses,
+                deaths = excluded.deaths,
+                outcome = excluded.outcome",
+            rusqlite::params![
+                run.get_uuid().to_string(),
+                run.get_started_at() as i64,
+                run.get_ended_at().map(|t| t as i64),
+                run.get_nightlord(),
+                night_bosses,
+                deaths,
+                run.get_outcome().to_db_str()
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Toutes les expéditions, de la plus récente à la plus ancienne
+    #[instrument(level = "debug", err)]
+    pub fn load_runs() -> Result<Vec<Run>, String> {
+        let conn = Self::open()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT uuid, started_at, ended_at, nightlord, night_bosses, deaths, outcome
+                 FROM runs ORDER BY started_at DESC",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let runs = stmt
+            .query_map([], |row| {
+                let uuid: String = row.get(0)?;
+                let started_at: i64 = row.get(1)?;
+                let ended_at: Option<i64> = row.get(2)?;
+                let nightlord: Option<String> = row.get(3)?;
+                let night_bosses: String = row.get(4)?;
+                let deaths: String = row.get(5)?;
+                let outcome: String = row.get(6)?;
+                Ok((
+                    uuid,
+                    started_at,
+                    ended_at,
+                    nightlord,
+                    night_bosses,
+                    deaths,
+                    outcome,
+                ))
+            })
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .map(
+                |(uuid, started_at, ended_at, nightlord, night_bosses, deaths, outcome)| {
+                    Run::from_db(
+                        uuid,
+                        started_at as u64,
+                        ended_at.map(|t| t as u64),
+                        nightlord,
+                        serde_json::from_str(&night_bosses).unwrap_or_default(),
+                        serde_json::from_str(&deaths).unwrap_or_default(),
+                        RunOutcome::from_db_str(&outcome),
+                    )
+                },
+            )
+            .collect();
+
+        Ok(runs)
+    }
+
     // -------------------------
     // Settings
     // -------------------------
 
+    #[instrument(level = "debug", skip_all, err)]
     pub fn save_settings(settings: &Settings) -> Result<(), String> {
         let conn = Self::open()?;
         let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
@@ -1,18 +1,39 @@
 use crate::hotkey::{GlobalHotkey, Key, Modifier, WindowsHotkey};
-use crate::ocr::ocr::{detect_death, get_boss_names};
+use crate::ocr::ocr::{detect_death_in_zone, get_boss_names, read_boss_zones};
 use crate::screens::components::list::ListMessage;
 use crate::screens::components::ocr::OcrMessage;
-use crate::screens::components::ocr::{ActionOCR, StatusOCR};
+use crate::screens::components::ocr::{ActionOCR, DeathId, StatusOCR};
+use crate::screens::vod_screen::VodMessage;
 
+use crate::structs::settings::capture_source::CaptureSource;
 use crate::structs::settings::game::GameConfig;
-use crate::utils::screen_capture::capture_full_screen;
+use crate::utils::boss_cache::{BossNameCache, NameCheck, sight_boss_bars};
+use crate::utils::frame_diff::{FrameDiff, thumbnail};
+use crate::utils::ocr_health::OcrHealth;
+use crate::utils::scan_budget::{ScanBudget, ScanStage};
+use crate::vod::vod::analyze_vod;
 use iced::Subscription;
 use iced::{stream, time::Duration};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::thread::spawn;
 use std::time::Instant;
 use tokio::task::yield_now;
 
 use tokio::sync::mpsc::unbounded_channel;
+use tracing::{Instrument, debug, debug_span, error, info, info_span, trace, warn};
+
+/// Lecture des noms de boss lancée pendant un combat : empreintes des barres, type de
+/// lecture et noms lus
+type FightRead = tokio::task::JoinHandle<(Vec<u128>, NameCheck, Vec<String>)>;
+
+/// Prochain numéro de mort, partagé par tous les workers : un worker relancé (changement
+/// de jeu, de budget CPU) ne réutilise jamais le numéro d'une mort encore en attente
+static NEXT_DEATH_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_death_id() -> DeathId {
+    NEXT_DEATH_ID.fetch_add(1, Ordering::Relaxed)
+}
 
 //SUBSCRIPTIONS
 pub fn hotkey_subscription() -> Subscription<ListMessage> {
@@ -20,14 +41,33 @@ pub fn hotkey_subscription() -> Subscription<ListMessage> {
 }
 
 pub fn ocr_subscription(
-    screen: i8,
+    source: CaptureSource,
     game_config: GameConfig,
-    death_text: String,
+    death_texts: Vec<String>,
+    cpu_budget: u8,
 ) -> Subscription<OcrMessage> {
     Subscription::run_with(
-        (screen, game_config.clone(), death_text),
-        move |(screen, game_config, death_text)| {
-            ocr_worker(*screen, game_config.clone(), death_text.clone())
+        (source, game_config.clone(), death_texts, cpu_budget),
+        move |(source, game_config, death_texts, cpu_budget)| {
+            ocr_worker(
+                source.clone(),
+                game_config.clone(),
+                death_texts.clone(),
+                *cpu_budget,
+            )
+        },
+    )
+}
+
+pub fn vod_subscription(
+    path: PathBuf,
+    game_config: GameConfig,
+    death_texts: Vec<String>,
+) -> Subscription<VodMessage> {
+    Subscription::run_with(
+        (path, game_config, death_texts),
+        move |(path, game_config, death_texts)| {
+            vod_worker(path.clone(), game_config.clone(), death_texts.clone())
         },
     )
 }
@@ -50,200 +90,505 @@ pub fn hotkey_worker() -> impl iced::futures::Stream<Item = ListMessage> {
 
     stream::channel(
         100,
-        |mut output: iced::futures::channel::mpsc::Sender<ListMessage>| async move {
-            println!("🎧 Démarrage du hotkey worker...");
+        |mut output: iced::futures::channel::mpsc::Sender<ListMessage>| {
+            async move {
+                info!("🎧 Démarrage du hotkey worker...");
 
-            // Créer le channel tokio pour recevoir les MessageApp du thread Windows
-            let (hotkey_tx, mut hotkey_rx) = unbounded_channel();
+                // Créer le channel tokio pour recevoir les MessageApp du thread Windows
+                let (hotkey_tx, mut hotkey_rx) = unbounded_channel();
 
-            // Spawn le thread Windows qui envoie déjà des MessageApp::Increment
-            spawn(move || {
-                let hotkey_manager = WindowsHotkey::new(hotkey_tx);
+                // Spawn le thread Windows qui envoie déjà des MessageApp::Increment
+                spawn(move || {
+                    let _span = info_span!("hotkey_thread").entered();
+                    let hotkey_manager = WindowsHotkey::new(hotkey_tx);
 
-                match hotkey_manager.register(&[Modifier::Alt], Key::Plus) {
-                    Ok(_) => println!("✅ Hotkey SHIFT+Plus registered"),
-                    Err(e) => eprintln!("❌ Register failed: {:?}", e),
-                }
-
-                println!("🔄 Démarrage de l'event loop Windows...");
-                hotkey_manager.event_loop();
-            });
-
-            // Boucle simple : transférer les MessageApp du thread Windows vers Iced
-            loop {
-                match hotkey_rx.recv().await {
-                    Some(msg) => {
-                        // Le message est déjà un MessageApp::Increment, on le transfère tel quel
-                        let _ = output.send(ListMessage::HotKey(msg)).await;
+                    match hotkey_manager.register(&[Modifier::Alt], Key::Plus) {
+                        Ok(_) => info!("✅ Hotkey SHIFT+Plus registered"),
+                        Err(e) => error!("❌ Register failed: {:?}", e),
                     }
-                    None => {
-                        println!("❌ Channel hotkey fermé");
-                        break;
+
+                    info!("🔄 Démarrage de l'event loop Windows...");
+                    hotkey_manager.event_loop();
+                });
+
+                // Boucle simple : transférer les MessageApp du thread Windows vers Iced
+                loop {
+                    match hotkey_rx.recv().await {
+                        Some(msg) => {
+                            debug!("⌨️ Hotkey reçu : {:?}", msg);
+                            // Le message est déjà un MessageApp::Increment, on le transfère tel quel
+                            let _ = output.send(ListMessage::HotKey(msg)).await;
+                        }
+                        None => {
+                            error!("❌ Channel hotkey fermé");
+                            break;
+                        }
                     }
                 }
-            }
 
-            println!("⚠️ Hotkey worker terminé");
+                warn!("⚠️ Hotkey worker terminé");
+            }
+            .instrument(info_span!("hotkey_worker"))
         },
     )
 }
 
 pub fn ocr_worker(
-    screen: i8,
+    source: CaptureSource,
     game_config: GameConfig,
-    death_text: String,
+    death_texts: Vec<String>,
+    cpu_budget: u8,
 ) -> impl iced::futures::Stream<Item = OcrMessage> {
     use iced::futures::sink::SinkExt;
 
     stream::channel(
         100,
-        move |mut output: iced::futures::channel::mpsc::Sender<OcrMessage>| async move {
-            println!("🎧 Démarrage du OCR worker (détection mort)...");
-
-            let _ = output.send(OcrMessage::ActivateOCR(true)).await;
-            //tokio::time::sleep(Duration::from_secs(3)).await;
-
-            let mut last_death_time = Instant::now();
-            let _ = output
-                .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
-                    ActionOCR::SearchingDeath,
-                )))
-                .await;
-            let mut start = true;
-            let target_interval = Duration::from_millis(500); // 500ms = 2 scans/seconde
-            let target_sleep_after_death = Duration::from_secs(10);
-            let mut status = ActionOCR::SearchingDeath;
-            let death_zone = game_config.get_death_zone().clone();
-            let boss_zones = game_config.get_boss_zones().clone();
-            loop {
-                let mut found_death = false;
-                if let ActionOCR::EndingAction = status {
-                    let _ = output
-                        .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
-                            ActionOCR::SearchingDeath,
-                        )))
-                        .await;
-                    status = ActionOCR::SearchingDeath;
-                }
+        move |mut output: iced::futures::channel::mpsc::Sender<OcrMessage>| {
+            async move {
+                info!("🎧 Démarrage du OCR worker (détection mort)...");
 
-                let loop_start = Instant::now();
-                let full_screen = match capture_full_screen(screen.clone()).await {
-                    Ok(img) => img,
+                let _ = output.send(OcrMessage::ActivateOCR(true)).await;
+                // Les lectures de boss d'un worker précédent n'arriveront plus
+                let _ = output.send(OcrMessage::WorkerStarted).await;
+                //tokio::time::sleep(Duration::from_secs(3)).await;
+
+                let mut frame_source = match source.open() {
+                    Ok(frame_source) => frame_source,
                     Err(e) => {
-                        eprintln!("❌ Erreur capture: {}", e);
-                        continue;
+                        error!("❌ Erreur ouverture source: {}", e);
+                        let _ = output.send(OcrMessage::ActivateOCR(false)).await;
+                        return;
                     }
                 };
-                match detect_death(&full_screen, &death_zone, death_text.clone()).await {
-                    Ok(true) => {
-                        found_death = true;
-                        println!("DetectDeath! after {:?}", loop_start.elapsed());
-                        println!(
-                            "Last death time : {:?}",
-                            last_death_time.elapsed().as_secs()
-                        );
-
-                        let test_death = last_death_time.elapsed().as_secs() > 5 || start;
-                        start = false;
-
-                        if test_death {
-                            println!("💀 MORT DÉTECTÉE !");
-
-                            // 🔥 SEND STATE CHANGE IMMEDIATELY
+                let live = frame_source.is_live();
+
+                // Horloge du worker : position dans le flux pour les vidéos, temps réel sinon
+                let worker_start = Instant::now();
+                let mut last_death_time: Option<Duration> = None;
+                let mut skip_until: Option<Duration> = None;
+                let mut health = OcrHealth::new();
+                // Noms des boss lus pendant le combat, réutilisés à la mort
+                let mut boss_cache = BossNameCache::new();
+                let mut pending_fight: Option<FightRead> = None;
+                let mut last_boss_check: Option<Duration> = None;
+                // Zone de mort inchangée : ni pré-filtre ni OCR, et scan ralenti si ça dure
+                let mut frame_diff = FrameDiff::new();
+                let _ = output
+                    .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
+                        ActionOCR::SearchingDeath,
+                    )))
+                    .await;
+                let target_interval = Duration::from_millis(500); // 500ms = 2 scans/seconde
+                // Cadence et prétraitement adaptés au temps mesuré de chaque étape
+                let mut budget = ScanBudget::new(target_interval, cpu_budget);
+                let target_sleep_after_death = Duration::from_secs(10);
+                let boss_check_interval = Duration::from_secs(1);
+                let mut status = ActionOCR::SearchingDeath;
+                let death_zone = game_config.get_death_zone().clone();
+                let color_profile = *game_config.get_color_profile();
+                let boss_zones = game_config.get_boss_zones().clone();
+                loop {
+                    let mut found_death = false;
+                    if let ActionOCR::EndingAction = status {
+                        let _ = output
+                            .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
+                                ActionOCR::SearchingDeath,
+                            )))
+                            .await;
+                        status = ActionOCR::SearchingDeath;
+                    }
+
+                    health.record_budget(budget.report());
+                    if let Some(report) = health.report_due() {
+                        let _ = output.send(OcrMessage::HealthReport(report)).await;
+                    }
+
+                    let loop_start = Instant::now();
+                    // Seule la zone de mort est capturée à chaque scan
+                    let (returned_source, frame) = match tokio::task::spawn_blocking(move || {
+                        let frame = frame_source.next_zone(&death_zone);
+                        (frame_source, frame)
+                    })
+                    .await
+                    {
+                        Ok(result) => result,
+                        Err(e) => {
+                            error!("❌ Erreur join: {}", e);
+                            let _ = output.send(OcrMessage::ActivateOCR(false)).await;
+                            break;
+                        }
+                    };
+                    frame_source = returned_source;
+                    budget.record(ScanStage::Capture, loop_start.elapsed());
+
+                    let scan = match frame {
+                        Ok(Some(scan)) => scan,
+                        Ok(None) => {
+                            if frame_source.is_exhausted() {
+                                info!("📼 Fin de la source d'images");
+                                let _ = output.send(OcrMessage::ActivateOCR(false)).await;
+                                break;
+                            }
+                            tokio::time::sleep(target_interval).await;
+                            continue;
+                        }
+                        Err(e) => {
+                            error!("❌ Erreur capture: {}", e);
+                            // Attente progressive : pas de boucle infinie si l'écran a disparu
+                            let delay = health.record_capture_error(e.clone());
+                            let _ = output
+                                .send(OcrMessage::ChangeActionOCR(StatusOCR::Error(e)))
+                                .await;
+                            tokio::time::sleep(delay).await;
+                            continue;
+                        }
+                    };
+
+                    #[cfg(feature = "debug")]
+                    {
+                        scan.zone.save("scan_death_zone.png").ok();
+                    }
+
+                    let now = frame_source
+                        .position()
+                        .unwrap_or_else(|| worker_start.elapsed());
+                    // Sources hors-ligne : on saute les images pendant le cooldown après une mort
+                    if skip_until.is_some_and(|until| now < until) {
+                        continue;
+                    }
+                    let death_start = Instant::now();
+                    let thumb = thumbnail(&scan.zone);
+                    let unchanged = frame_diff.is_unchanged(&thumb);
+                    let preprocessing = budget.preprocessing();
+                    let detection = if unchanged {
+                        Ok(false)
+                    } else {
+                        detect_death_in_zone(
+                            &scan.zone,
+                            &color_profile,
+                            &death_texts,
+                            preprocessing,
+                        )
+                        .await
+                    };
+                    budget.record(ScanStage::Death, death_start.elapsed());
+                    match detection {
+                        // Seules les images réellement analysées servent de référence
+                        Ok(false) if !unchanged => frame_diff.record_negative(thumb),
+                        Ok(true) => frame_diff.reset(),
+                        _ => {}
+                    }
+                    if detection.is_ok() {
+                        // Retour à la normale après une série d'erreurs
+                        if health.is_failing() {
                             let _ = output
                                 .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
-                                    ActionOCR::SearchingBossName,
+                                    ActionOCR::SearchingDeath,
                                 )))
                                 .await;
-                            let _ = output.send(OcrMessage::DeathDetected).await;
-                            // 🔑 allow UI/state reducer to run NOW
-                            yield_now().await;
-
-                            // 🔥 RUN BOSS OCR IN PARALLEL (no UI blocking)
-                            let mut output_clone = output.clone();
-                            let dyn_image_clone = full_screen.clone();
-                            let boss_zones_clone = boss_zones.clone();
-                            let handler = tokio::spawn(async move {
-                                println!(
-                                    "Elapsed before boss detection: {:?}",
-                                    loop_start.elapsed()
-                                );
-
-                                match get_boss_names(dyn_image_clone, boss_zones_clone).await {
-                                    Ok(bosses) => {
-                                        println!("⚔️ Boss trouvés : {:?}", bosses);
+                        }
+                        health.record_success();
+                    }
 
-                                        let _ = output_clone
-                                            .send(OcrMessage::BossesFoundOCR(bosses))
-                                            .await;
+                    // Barres de boss pendant le combat : noms lus une fois au début, ou retrouvés en cache et confirmés
+                    if let Some(handle) = pending_fight.take_if(|handle| handle.is_finished())
+                        && let Ok((hashes, check, names)) = handle.await
+                    {
+                        boss_cache.record(&hashes, check, names);
+                    }
+                    let boss_check_due = last_boss_check
+                        .is_none_or(|t| now.saturating_sub(t) >= boss_check_interval);
+                    if !matches!(detection, Ok(true)) && boss_check_due && pending_fight.is_none() {
+                        last_boss_check = Some(now);
+                        let bosses_start = Instant::now();
+                        let sighted = match scan.full.as_ref() {
+                            Some(full) => {
+                                let content = frame_source.content_area(full);
+                                sight_boss_bars(
+                                    boss_zones.iter().map(|zone| zone.crop_image(full, content)),
+                                )
+                            }
+                            None => {
+                                let zones = boss_zones.clone();
+                                match tokio::task::spawn_blocking(move || {
+                                    // Zones capturées une à une, arrêt à la première sans barre
+                                    let sighted = sight_boss_bars(zones.iter().map_while(|zone| {
+                                        frame_source.next_zone(zone).ok().flatten().map(|s| s.zone)
+                                    }));
+                                    (frame_source, sighted)
+                                })
+                                .await
+                                {
+                                    Ok((returned_source, sighted)) => {
+                                        frame_source = returned_source;
+                                        sighted
                                     }
                                     Err(e) => {
-                                        eprintln!("❌ Erreur détection boss : {}", e);
-                                        let _ = output_clone
-                                            .send(OcrMessage::BossesFoundOCR(vec![]))
-                                            .await;
+                                        error!("❌ Erreur join: {}", e);
+                                        let _ = output.send(OcrMessage::ActivateOCR(false)).await;
+                                        break;
                                     }
                                 }
+                            }
+                        };
+                        budget.record(ScanStage::Bosses, bosses_start.elapsed());
+                        if let Some(check) = boss_cache.observe(&sighted, now) {
+                            info!("⚔️ Combat : lecture des noms de boss ({:?})", check);
+                            let hashes: Vec<u128> = sighted.iter().map(|bar| bar.hash).collect();
+                            let crops = sighted.into_iter().map(|bar| bar.zone).collect();
+                            pending_fight = Some(tokio::spawn(
+                                async move {
+                                    let names =
+                                        read_boss_zones(crops, check.preprocessing(preprocessing))
+                                            .await
+                                            .unwrap_or_else(|e| {
+                                                error!("❌ Erreur lecture boss en combat : {}", e);
+                                                vec![]
+                                            });
+                                    (hashes, check, names)
+                                }
+                                .instrument(debug_span!("fight_ocr")),
+                            ));
+                        }
+                    } else {
+                        // Vérification ~1 fois/s : son coût est réparti sur les scans intermédiaires
+                        budget.skip(ScanStage::Bosses);
+                    }
+
+                    match detection {
+                        Ok(true) => {
+                            found_death = true;
+                            debug!("DetectDeath! after {:?}", loop_start.elapsed());
+                            debug!("Last death time : {:?}", last_death_time);
+
+                            let test_death = last_death_time
+                                .is_none_or(|t| now.saturating_sub(t) > Duration::from_secs(5));
+
+                            if test_death {
+                                info!("💀 MORT DÉTECTÉE !");
+                                let death_id = next_death_id();
 
-                                let _ = output_clone
+                                // 🔥 SEND STATE CHANGE IMMEDIATELY
+                                let _ = output
                                     .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
-                                        ActionOCR::EndingAction,
+                                        ActionOCR::SearchingBossName,
                                     )))
                                     .await;
-                            });
-                            match handler.await {
-                                Ok(_) => println!("✅ Boss OCR task finished successfully"),
-                                Err(e) => eprintln!("❌ Boss OCR task panicked: {}", e),
-                            }
+                                // La mort est comptée tout de suite, les boss arrivent ensuite
+                                let _ = output.send(OcrMessage::DeathDetected(death_id)).await;
+                                // 🔑 allow UI/state reducer to run NOW
+                                yield_now().await;
+
+                                // Combat suivi pendant le scan : noms déjà lus, pas de capture complète
+                                if let Some(handle) = pending_fight.take()
+                                    && let Ok((hashes, check, names)) = handle.await
+                                {
+                                    boss_cache.record(&hashes, check, names);
+                                }
+                                // PV restants des boss juste avant la mort (barres suivies pendant le combat)
+                                let fight = boss_cache.take_fight(now);
+                                let health = fight
+                                    .as_ref()
+                                    .map(|fight| fight.health.clone())
+                                    .unwrap_or_default();
+                                if let Some(bosses) = fight.and_then(|fight| fight.names) {
+                                    info!("⚔️ Boss trouvés (combat) : {:?} {:?}", bosses, health);
+                                    let _ = output
+                                        .send(OcrMessage::BossesFoundOCR(death_id, bosses, health))
+                                        .await;
+                                    let _ = output
+                                        .send(OcrMessage::ChangeActionOCR(StatusOCR::Started(
+                                            ActionOCR::EndingAction,
+                                        )))
+                                        .await;
+                                } else {
+                                    // Image entière seulement une fois la mort confirmée (barres de boss)
+                                    let full_screen = match scan.full {
+                                        Some(full) => Some(full),
+                                        None => match tokio::task::spawn_blocking(move || {
+                                            let frame = frame_source.next_frame();
+                                            (frame_source, frame)
+                                        })
+                                        .await
+                                        {
+                                            Ok((returned_source, frame)) => {
+                                                frame_source = returned_source;
+                                                frame.unwrap_or_else(|e| {
+                                                    error!("❌ Erreur capture complète: {}", e);
+                                                    None
+                                                })
+                                            }
+                                            Err(e) => {
+                                                error!("❌ Erreur join: {}", e);
+                                                let _ = output
+                                                    .send(OcrMessage::ActivateOCR(false))
+                                                    .await;
+                                                break;
+                                            }
+                                        },
+                                    };
+
+                                    // Zone utile validée par la source, pas mesurée sur l'écran de mort
+                                    let content = full_screen
+                                        .as_ref()
+                                        .map(|full| frame_source.content_area(full));
+
+                                    // 🔥 RUN BOSS OCR IN PARALLEL (no UI blocking)
+                                    let mut output_clone = output.clone();
+                                    let dyn_image_clone = full_screen.clone();
+                                    let boss_zones_clone = boss_zones.clone();
+                                    let handler = tokio::spawn(
+                                        async move {
+                                            debug!(
+                                                "Elapsed before boss detection: {:?}",
+                                                loop_start.elapsed()
+                                            );
+
+                                            let bosses = match (dyn_image_clone, content) {
+                                                (Some(image), Some(content)) => {
+                                                    get_boss_names(image, content, boss_zones_clone)
+                                                        .await
+                                                }
+                                                _ => {
+                                                    Err("Capture complète indisponible".to_string())
+                                                }
+                                            };
+                                            match bosses {
+                                                Ok(bosses) => {
+                                                    info!("⚔️ Boss trouvés : {:?}", bosses);
+
+                                                    let _ = output_clone
+                                                        .send(OcrMessage::BossesFoundOCR(
+                                                            death_id, bosses, health,
+                                                        ))
+                                                        .await;
+                                                }
+                                                Err(e) => {
+                                                    error!("❌ Erreur détection boss : {}", e);
+                                                    let _ = output_clone
+                                                        .send(OcrMessage::BossesFoundOCR(
+                                                            death_id,
+                                                            vec![],
+                                                            health,
+                                                        ))
+                                                        .await;
+                                                }
+                                            }
+
+                                            let _ = output_clone
+                                                .send(OcrMessage::ChangeActionOCR(
+                                                    StatusOCR::Started(ActionOCR::EndingAction),
+                                                ))
+                                                .await;
+                                        }
+                                        .instrument(info_span!("boss_ocr", death_id)),
+                                    );
+                                    match handler.await {
+                                        Ok(_) => debug!("✅ Boss OCR task finished successfully"),
+                                        Err(e) => error!("❌ Boss OCR task panicked: {}", e),
+                                    }
+                                }
 
-                            status = ActionOCR::EndingAction;
-                            last_death_time = Instant::now();
+                                status = ActionOCR::EndingAction;
+                                last_death_time = Some(
+                                    frame_source
+                                        .position()
+                                        .unwrap_or_else(|| worker_start.elapsed()),
+                                );
 
-                            // cooldown AFTER scheduling OCR
-                            //tokio::time::sleep(Duration::from_secs(8)).await;
-                            #[cfg(any(feature = "debug", feature = "timing"))]
-                            {
+                                // cooldown AFTER scheduling OCR
+                                //tokio::time::sleep(Duration::from_secs(8)).await;
                                 //let _ = output.send(MessageApp::ActivateOCR(false)).await;
-                                println!("End of Boss OCR task");
+                                debug!("End of Boss OCR task");
                             }
+
+                            debug!("end death detection {:?}", loop_start.elapsed());
                         }
 
-                        println!("end death detection {:?}", loop_start.elapsed());
+                        Ok(false) => {}
+
+                        Err(e) => {
+                            error!("❌ Erreur OCR : {}", e);
+                            let delay = health.record_ocr_error(e.clone());
+                            let _ = output
+                                .send(OcrMessage::ChangeActionOCR(StatusOCR::Error(e)))
+                                .await;
+                            tokio::time::sleep(delay).await;
+                        }
                     }
 
-                    Ok(false) => {}
+                    if !live {
+                        if found_death {
+                            skip_until = Some(now + target_sleep_after_death);
+                        }
+                        continue;
+                    }
 
-                    Err(e) => {
-                        eprintln!("❌ Erreur OCR : {}", e);
-                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    let elapsed = loop_start.elapsed();
+                    let interval = if found_death {
+                        target_sleep_after_death
+                    } else {
+                        frame_diff
+                            .scan_interval(target_interval)
+                            .max(budget.adjust())
+                    };
+                    if elapsed < interval {
+                        let sleep_duration = interval - elapsed;
+                        trace!("⏱️ OCR: {:?}, Sleep: {:?}", elapsed, sleep_duration);
+                        tokio::time::sleep(sleep_duration).await;
+                    } else {
+                        debug!("⚠️ OCR trop lent: {:?} (target: {:?})", elapsed, interval);
+                        // Pas de sleep, continuer directement
                     }
                 }
+            }
+            .instrument(info_span!("ocr_worker"))
+        },
+    )
+}
 
-                let elapsed = loop_start.elapsed();
-                let interval = if found_death {
-                    target_sleep_after_death
-                } else {
-                    target_interval
-                };
-                if elapsed < interval {
-                    let sleep_duration = interval - elapsed;
-                    #[cfg(feature = "timing")]
-                    {
-                        println!("⏱️ OCR: {:?}, Sleep: {:?}", elapsed, sleep_duration);
-                    }
-                    tokio::time::sleep(sleep_duration).await;
-                } else {
-                    #[cfg(feature = "timing")]
-                    {
-                        println!(
-                            "⚠️ OCR trop lent: {:?} (target: {:?})",
-                            elapsed, target_interval
-                        );
-                    }
+// Worker d'analyse d'une VOD
+//
+// L'analyse (rayon + ffmpeg) est bloquante : elle tourne dans spawn_blocking et
+// remonte ses événements via tokio::mpsc, comme le thread Windows des hotkeys.
+pub fn vod_worker(
+    path: PathBuf,
+    game_config: GameConfig,
+    death_texts: Vec<String>,
+) -> impl iced::futures::Stream<Item = VodMessage> {
+    use iced::futures::sink::SinkExt;
 
-                    // Pas de sleep, continuer directement
+    stream::channel(
+        100,
+        move |mut output: iced::futures::channel::mpsc::Sender<VodMessage>| {
+            async move {
+                info!("📼 Analyse de la VOD {:?}...", path);
+
+                let (event_tx, mut event_rx) = unbounded_channel();
+                let handle = tokio::runtime::Handle::current();
+
+                let analysis = tokio::task::spawn_blocking(move || {
+                    // L'envoi échoue quand la subscription est abandonnée : on arrête l'analyse
+                    analyze_vod(&path, &game_config, &death_texts, &handle, |event| {
+                        event_tx.send(event).is_ok()
+                    })
+                });
+
+                while let Some(event) = event_rx.recv().await {
+                    let _ = output.send(VodMessage::Event(event)).await;
+                }
+
+                let result = match analysis.await {
+                    Ok(Ok(deaths)) => Ok(deaths.len()),
+                    Ok(Err(e)) => Err(e),
+                    Err(e) => Err(format!("Erreur join: {}", e)),
+                };
+                if let Err(e) = &result {
+                    error!("❌ Erreur analyse VOD : {}", e);
                 }
+                let _ = output.send(VodMessage::AnalysisFinished(result)).await;
             }
+            .instrument(info_span!("vod_worker"))
         },
     )
 }
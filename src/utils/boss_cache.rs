@@ -0,0 +1,712 @@
+// src/utils/boss_cache.rs
+//
+// Reconnaissance anticipée des boss : pendant le combat la barre de vie est bien visible,
+// alors qu'à l'écran de mort elle est souvent déjà en train de disparaître.
+// Le nom est lu une fois au début du combat puis mis en cache, indexé par une empreinte
+// perceptuelle du texte du nom (insensible à la barre qui se vide). Un nom retrouvé en
+// cache est confirmé par une lecture rapide avant d'être attribué à une mort.
+// Le remplissage des barres est suivi pendant tout le combat : PV restants à la mort,
+// mesurés par rapport à la longueur du fond de la barre (juste même si la barre
+// n'est aperçue qu'en milieu de combat).
+
+use crate::structs::encounter::same_bosses;
+use crate::utils::scan_budget::Preprocessing;
+use image::DynamicImage;
+use image::imageops::FilterType;
+use std::time::Duration;
+use tracing::info;
+
+/// Pixel de barre de vie : rouge dominant
+const BAR_MIN_RED: u8 = 80;
+/// Une ligne fait partie de la barre si elle contient un segment rouge continu
+/// couvrant au moins cette fraction de la largeur
+const BAR_MIN_RUN: f32 = 0.30;
+/// Fond de la barre (partie vide) : sombre…
+const TRACK_MAX_LEVEL: u8 = 70;
+/// … et uni : écart maximal (par canal) avec le début du fond, au-delà c'est le décor
+const TRACK_TOLERANCE: u8 = 16;
+/// Partie jaune pendant que les PV perdus s'effacent
+const TRAIL_MIN_GREEN: u8 = 100;
+/// La barre est fine : au-delà de cette fraction de la hauteur, c'est le décor
+const BAR_MAX_THICKNESS: f32 = 0.5;
+/// Hauteur minimale (fraction de la zone) au-dessus de la barre pour contenir le nom
+const NAME_MIN_HEIGHT: f32 = 0.25;
+
+/// Pixel de texte du nom : clair
+const TEXT_MIN_LUMA: u8 = 160;
+/// Miniature de l'empreinte : (HASH_WIDTH + 1) x HASH_HEIGHT, un bit par gradient horizontal
+const HASH_WIDTH: u32 = 16;
+const HASH_HEIGHT: u32 = 8;
+/// Bits différents tolérés entre deux empreintes du même nom (compression, animation)
+const HASH_TOLERANCE: u32 = 12;
+/// Noms gardés en cache
+const CACHE_CAPACITY: usize = 64;
+/// Durée pendant laquelle le combat reste attribuable après la disparition des barres
+const FIGHT_GRACE: Duration = Duration::from_secs(15);
+
+fn is_bar_pixel(r: u8, g: u8, b: u8) -> bool {
+    r >= BAR_MIN_RED && r / 2 > g && r / 2 > b
+}
+
+fn is_track_pixel(r: u8, g: u8, b: u8) -> bool {
+    r.max(g).max(b) <= TRACK_MAX_LEVEL
+}
+
+fn is_trail_pixel(r: u8, g: u8, b: u8) -> bool {
+    r >= BAR_MIN_RED && g >= TRAIL_MIN_GREEN && b < g / 2
+}
+
+fn same_shade(a: [u8; 3], b: [u8; 3]) -> bool {
+    a.iter().zip(&b).all(|(a, b)| a.abs_diff(*b) <= TRACK_TOLERANCE)
+}
+
+/// Barre de vie trouvée dans une zone de boss
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HealthBar {
+    /// Ligne du haut de la barre (le nom est au-dessus)
+    pub top: u32,
+    /// Longueur de la partie remplie, en pixels
+    pub filled: u32,
+    /// Longueur de la barre pleine : partie remplie suivie du fond vide
+    pub track: u32,
+}
+
+/// Barre de vie d'une zone de boss, `None` si aucune barre
+pub fn find_health_bar(zone: &DynamicImage) -> Option<HealthBar> {
+    let rgba = zone.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let min_run = ((width as f32 * BAR_MIN_RUN) as u32).max(1);
+    // (ligne, plus long segment rouge, fond vide qui le prolonge) pour chaque ligne de la barre
+    let bar_rows: Vec<(u32, u32, u32)> = (0..height)
+        .filter_map(|y| {
+            let pixel = |x: u32| rgba.get_pixel(x, y).0;
+            let (mut run, mut best, mut end) = (0u32, 0u32, 0u32);
+            for x in 0..width {
+                let [r, g, b, _] = pixel(x);
+                run = if is_bar_pixel(r, g, b) { run + 1 } else { 0 };
+                if run > best {
+                    (best, end) = (run, x + 1);
+                }
+            }
+            // Fond vide : d'une seule teinte sombre, le décor sombre qui suit n'en fait pas partie
+            let mut shade = None;
+            let empty = (end..width)
+                .take_while(|x| {
+                    let [r, g, b, _] = pixel(*x);
+                    if is_trail_pixel(r, g, b) {
+                        return true;
+                    }
+                    is_track_pixel(r, g, b) && same_shade([r, g, b], *shade.get_or_insert([r, g, b]))
+                })
+                .count() as u32;
+            (best >= min_run).then_some((y, best, empty))
+        })
+        .collect();
+
+    let (top, _, _) = *bar_rows.first()?;
+    let thick_enough = bar_rows.len() >= 2;
+    let thin_enough = bar_rows.len() as f32 <= height as f32 * BAR_MAX_THICKNESS;
+    let room_for_name = top as f32 >= height as f32 * NAME_MIN_HEIGHT;
+    let (_, filled, empty) = *bar_rows.iter().max_by_key(|(_, run, _)| *run)?;
+    (thick_enough && thin_enough && room_for_name).then_some(HealthBar {
+        top,
+        filled,
+        track: filled + empty,
+    })
+}
+
+/// Empreinte perceptuelle (dHash 128 bits) : gradients horizontaux d'une miniature 17x8
+pub fn perceptual_hash(image: &DynamicImage) -> u128 {
+    let small = image
+        .resize_exact(HASH_WIDTH + 1, HASH_HEIGHT, FilterType::Triangle)
+        .to_luma8();
+    let mut hash = 0u128;
+    for y in 0..HASH_HEIGHT {
+        for x in 0..HASH_WIDTH {
+            let bit = small.get_pixel(x, y)[0] < small.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | bit as u128;
+        }
+    }
+    hash
+}
+
+/// Rectangle (x, y, largeur, hauteur) englobant les pixels clairs du texte
+fn text_bounds(strip: &DynamicImage) -> Option<(u32, u32, u32, u32)> {
+    let luma = strip.to_luma8();
+    let (mut left, mut top, mut right, mut bottom) = (u32::MAX, u32::MAX, 0, 0);
+    for (x, y, pixel) in luma.enumerate_pixels() {
+        if pixel[0] >= TEXT_MIN_LUMA {
+            (left, top) = (left.min(x), top.min(y));
+            (right, bottom) = (right.max(x), bottom.max(y));
+        }
+    }
+    (left <= right).then(|| (left, top, right - left + 1, bottom - top + 1))
+}
+
+/// Empreinte du nom : seul le texte compte, pas sa position ni le décor autour
+pub fn name_hash(strip: &DynamicImage) -> u128 {
+    match text_bounds(strip) {
+        Some((x, y, width, height)) => perceptual_hash(&strip.crop_imm(x, y, width, height)),
+        None => perceptual_hash(strip),
+    }
+}
+
+fn distance(a: u128, b: u128) -> u32 {
+    (a ^ b).count_ones()
+}
+
+fn same_name(a: u128, b: u128) -> bool {
+    distance(a, b) <= HASH_TOLERANCE
+}
+
+/// Lecture OCR des noms demandée par le cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameCheck {
+    /// Noms inconnus : lecture complète
+    Read,
+    /// Noms retrouvés en cache : une passe rapide pour les confirmer
+    Confirm,
+}
+
+impl NameCheck {
+    /// Prétraitement de la lecture : une seule variante suffit pour confirmer
+    pub fn preprocessing(self, current: Preprocessing) -> Preprocessing {
+        match self {
+            NameCheck::Read => current,
+            NameCheck::Confirm => Preprocessing {
+                boss_variants: 1,
+                ..current
+            },
+        }
+    }
+}
+
+/// Zone de boss avec une barre visible
+pub struct SightedBar {
+    pub zone: DynamicImage,
+    /// Empreinte du nom
+    pub hash: u128,
+    pub filled: u32,
+    pub track: u32,
+}
+
+/// Zones de boss avec une barre visible, de la première zone jusqu'à la première
+/// sans barre (l'itérateur n'est pas consommé au-delà)
+pub fn sight_boss_bars(zones: impl IntoIterator<Item = DynamicImage>) -> Vec<SightedBar> {
+    zones
+        .into_iter()
+        .map_while(|zone| {
+            let bar = find_health_bar(&zone)?;
+            let hash = name_hash(&zone.crop_imm(0, 0, zone.width(), bar.top));
+            Some(SightedBar {
+                zone,
+                hash,
+                filled: bar.filled,
+                track: bar.track,
+            })
+        })
+        .collect()
+}
+
+/// Combat en cours : empreintes des noms (une par barre) et noms une fois lus ou confirmés
+#[derive(Debug, Clone)]
+struct Fight {
+    hashes: Vec<u128>,
+    names: Option<Vec<String>>,
+    /// Noms du cache démentis par la lecture rapide : lecture complète à faire
+    reread: bool,
+    last_seen: Duration,
+    /// Dernière mesure (remplissage, longueur de la barre pleine) de chaque barre
+    bars: Vec<(u32, u32)>,
+}
+
+impl Fight {
+    /// PV restants de chaque barre (0.0 – 1.0), relatifs à la barre pleine
+    fn health(&self) -> Vec<f32> {
+        self.bars
+            .iter()
+            .map(|(filled, track)| (*filled as f32 / (*track).max(1) as f32).min(1.0))
+            .collect()
+    }
+}
+
+/// Combat terminé par une mort
+#[derive(Debug, Clone, PartialEq)]
+pub struct FightSummary {
+    /// Noms lus en début de combat, ou retrouvés en cache et confirmés
+    pub names: Option<Vec<String>>,
+    /// PV restants de chaque barre juste avant la mort
+    pub health: Vec<f32>,
+}
+
+#[derive(Debug, Default)]
+pub struct BossNameCache {
+    names: Vec<(u128, String)>,
+    fight: Option<Fight>,
+}
+
+impl BossNameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nom dont l'empreinte est la plus proche, dans la tolérance
+    fn lookup(&self, hash: u128) -> Option<&String> {
+        self.names
+            .iter()
+            .filter(|(known, _)| same_name(*known, hash))
+            .min_by_key(|(known, _)| distance(*known, hash))
+            .map(|(_, name)| name)
+    }
+
+    /// Barres vues à l'instant `now` ; renvoie la lecture des noms à lancer, s'il y en a une
+    pub fn observe(&mut self, bars: &[SightedBar], now: Duration) -> Option<NameCheck> {
+        if bars.is_empty() {
+            return None;
+        }
+        let hashes: Vec<u128> = bars.iter().map(|bar| bar.hash).collect();
+        let measures: Vec<(u32, u32)> = bars.iter().map(|bar| (bar.filled, bar.track)).collect();
+
+        if let Some(fight) = self.fight.as_mut() {
+            let same_fight = fight.hashes.len() == hashes.len()
+                && fight
+                    .hashes
+                    .iter()
+                    .zip(&hashes)
+                    .all(|(a, b)| same_name(*a, *b));
+            if same_fight {
+                fight.last_seen = now;
+                fight.bars = measures;
+                return std::mem::take(&mut fight.reread).then_some(NameCheck::Read);
+            }
+        }
+
+        // Nouveau combat (ou nouvelle barre) : le cache suffit-il ?
+        let cached: Option<Vec<String>> = hashes.iter().map(|h| self.lookup(*h).cloned()).collect();
+        let check = match cached {
+            Some(names) => {
+                info!("⚔️ Boss reconnus (cache) : {:?}, confirmation", names);
+                NameCheck::Confirm
+            }
+            None => NameCheck::Read,
+        };
+        self.fight = Some(Fight {
+            hashes,
+            names: None,
+            reread: false,
+            last_seen: now,
+            bars: measures,
+        });
+        Some(check)
+    }
+
+    /// Noms lus par l'OCR pour les barres `hashes` (dans le même ordre)
+    pub fn record(&mut self, hashes: &[u128], check: NameCheck, names: Vec<String>) {
+        let names = match check {
+            NameCheck::Read => {
+                for (hash, name) in hashes.iter().zip(&names) {
+                    if self.lookup(*hash).is_none() {
+                        self.names.push((*hash, name.clone()));
+                    }
+                }
+                if self.names.len() > CACHE_CAPACITY {
+                    self.names.drain(..self.names.len() - CACHE_CAPACITY);
+                }
+                Some(names).filter(|names| !names.is_empty())
+            }
+            NameCheck::Confirm => {
+                let cached: Option<Vec<String>> =
+                    hashes.iter().map(|h| self.lookup(*h).cloned()).collect();
+                match cached {
+                    Some(cached) if same_bosses(&cached, &names) => Some(cached),
+                    _ => {
+                        info!("⚠️ Boss du cache non confirmés (lu : {:?})", names);
+                        self.names
+                            .retain(|(known, _)| !hashes.iter().any(|h| same_name(*known, *h)));
+                        None
+                    }
+                }
+            }
+        };
+
+        if let Some(fight) = self.fight.as_mut()
+            && fight.hashes == hashes
+        {
+            fight.reread = names.is_none() && check == NameCheck::Confirm;
+            fight.names = names;
+        }
+    }
+
+    /// Combat en cours au moment d'une mort ; le combat est terminé
+    pub fn take_fight(&mut self, now: Duration) -> Option<FightSummary> {
+        let fight = self.fight.take()?;
+        if now.saturating_sub(fight.last_seen) > FIGHT_GRACE {
+            return None;
+        }
+        Some(FightSummary {
+            health: fight.health(),
+            names: fight.names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    /// Police 5x7 des noms de test
+    const FONT: &[(char, [&str; 7])] = &[
+        (
+            'A',
+            [
+                " ### ", "#   #", "#   #", "#####", "#   #", "#   #", "#   #",
+            ],
+        ),
+        (
+            'B',
+            [
+                "#### ", "#   #", "#   #", "#### ", "#   #", "#   #", "#### ",
+            ],
+        ),
+        (
+            'C',
+            [
+                " ### ", "#   #", "#    ", "#    ", "#    ", "#   #", " ### ",
+            ],
+        ),
+        (
+            'D',
+            [
+                "#### ", "#   #", "#   #", "#   #", "#   #", "#   #", "#### ",
+            ],
+        ),
+        (
+            'E',
+            [
+                "#####", "#    ", "#    ", "#### ", "#    ", "#    ", "#####",
+            ],
+        ),
+        (
+            'F',
+            [
+                "#####", "#    ", "#    ", "#### ", "#    ", "#    ", "#    ",
+            ],
+        ),
+        (
+            'G',
+            [
+                " ### ", "#   #", "#    ", "# ###", "#   #", "#   #", " ####",
+            ],
+        ),
+        (
+            'H',
+            [
+                "#   #", "#   #", "#   #", "#####", "#   #", "#   #", "#   #",
+            ],
+        ),
+        (
+            'I',
+            [
+                " ### ", "  #  ", "  #  ", "  #  ", "  #  ", "  #  ", " ### ",
+            ],
+        ),
+        (
+            'K',
+            [
+                "#   #", "#  # ", "# #  ", "##   ", "# #  ", "#  # ", "#   #",
+            ],
+        ),
+        (
+            'L',
+            [
+                "#    ", "#    ", "#    ", "#    ", "#    ", "#    ", "#####",
+            ],
+        ),
+        (
+            'M',
+            [
+                "#   #", "## ##", "# # #", "# # #", "#   #", "#   #", "#   #",
+            ],
+        ),
+        (
+            'N',
+            [
+                "#   #", "##  #", "# # #", "#  ##", "#   #", "#   #", "#   #",
+            ],
+        ),
+        (
+            'O',
+            [
+                " ### ", "#   #", "#   #", "#   #", "#   #", "#   #", " ### ",
+            ],
+        ),
+        (
+            'R',
+            [
+                "#### ", "#   #", "#   #", "#### ", "# #  ", "#  # ", "#   #",
+            ],
+        ),
+        (
+            'S',
+            [
+                " ####", "#    ", "#    ", " ### ", "    #", "    #", "#### ",
+            ],
+        ),
+        (
+            'T',
+            [
+                "#####", "  #  ", "  #  ", "  #  ", "  #  ", "  #  ", "  #  ",
+            ],
+        ),
+        (
+            ',',
+            [
+                "     ", "     ", "     ", "     ", "  ## ", "   # ", "  #  ",
+            ],
+        ),
+    ];
+
+    /// Noms de boss de même longueur (19 caractères)
+    const MOHG: &str = "MOHG, LORD OF BLOOD";
+    const GODRICK: &str = "GODRICK THE GRAFTED";
+    const MISBEGOTTEN: &str = "LEONINE MISBEGOTTEN";
+
+    fn glyph_pixel(text: &str, x: u32, y: u32) -> bool {
+        let (column, row) = ((x / 6) as usize, y as usize);
+        let Some(c) = text.chars().nth(column) else {
+            return false;
+        };
+        FONT.iter()
+            .find(|(glyph, _)| *glyph == c)
+            .is_some_and(|(_, rows)| {
+                row < 7 && rows[row].as_bytes().get(x as usize % 6) == Some(&b'#')
+            })
+    }
+
+    /// Bruit de compression déterministe (-12 ..= 12)
+    fn noise(x: u32, y: u32, seed: u32) -> i32 {
+        let n = (x * 7919 + y * 104_729 + seed * 1_299_709).wrapping_mul(2_654_435_761);
+        (n >> 27) as i32 * 24 / 31 - 12
+    }
+
+    fn shade(level: [u8; 3], n: i32) -> Rgba<u8> {
+        let [r, g, b] = level.map(|c| (c as i32 + n).clamp(0, 255) as u8);
+        Rgba([r, g, b, 255])
+    }
+
+    /// Zone de boss de 320x48 : nom en police x2 décalé de `dx`, barre rouge remplie à
+    /// `fill` sur un fond sombre de 300 px entouré du décor
+    fn boss_zone_at(name: &str, fill: f32, dx: u32) -> DynamicImage {
+        boss_zone_in(name, fill, dx, [140, 120, 90])
+    }
+
+    /// Même zone dans une arène sombre : décor texturé, aussi sombre que le fond de la barre
+    fn dark_arena_zone(fill: f32) -> DynamicImage {
+        let zone = boss_zone_in(MOHG, fill, 0, [0, 0, 0]);
+        let mut rgba = zone.to_rgba8();
+        for (x, y, pixel) in rgba.enumerate_pixels_mut() {
+            if (36..42).contains(&y) && !(10..310).contains(&x) {
+                let level = if (x / 3 + y) % 2 == 0 { 12 } else { 62 };
+                *pixel = shade([level, level - 8, level - 10], noise(x, y, 1) / 3);
+            }
+        }
+        DynamicImage::ImageRgba8(rgba)
+    }
+
+    fn boss_zone_in(name: &str, fill: f32, dx: u32, decor: [u8; 3]) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(320, 48, |x, y| {
+            let n = noise(x, y, dx);
+            if y < 36 {
+                let on =
+                    x >= 12 + dx && y >= 6 && glyph_pixel(name, (x - 12 - dx) / 2, (y - 6) / 2);
+                if on {
+                    shade([225, 218, 205], n)
+                } else {
+                    shade([30 + y as u8, 26 + y as u8, 24], n)
+                }
+            } else if (36..42).contains(&y) {
+                if !(10..310).contains(&x) {
+                    shade(decor, n)
+                } else if ((x - 10) as f32) < 300.0 * fill {
+                    shade([150, 20, 20], n / 2)
+                } else {
+                    shade([40, 30, 30], n / 2)
+                }
+            } else {
+                shade([25, 22, 20], n)
+            }
+        }))
+    }
+
+    fn boss_zone(name: &str, fill: f32) -> DynamicImage {
+        boss_zone_at(name, fill, 0)
+    }
+
+    /// Barre vue sans image (seules l'empreinte et la longueur comptent pour le cache)
+    fn bar(hash: u128, filled: u32) -> SightedBar {
+        SightedBar {
+            zone: DynamicImage::new_rgba8(1, 1),
+            hash,
+            filled,
+            track: 200,
+        }
+    }
+
+    #[test]
+    fn health_bar_found_and_name_hash_ignores_remaining_health() {
+        let full = boss_zone(MOHG, 1.0);
+        let nearly_dead = boss_zone(MOHG, 0.35);
+        assert_eq!(
+            find_health_bar(&full),
+            Some(HealthBar {
+                top: 36,
+                filled: 300,
+                track: 300,
+            })
+        );
+        assert_eq!(
+            find_health_bar(&nearly_dead).map(|b| (b.filled, b.track)),
+            Some((105, 300))
+        );
+        assert_eq!(find_health_bar(&boss_zone(MOHG, 0.0)), None);
+
+        let seen = sight_boss_bars([
+            full,
+            nearly_dead,
+            boss_zone(GODRICK, 0.0),
+            boss_zone(MOHG, 1.0),
+        ]);
+        assert_eq!(seen.len(), 2, "arrêt à la première zone sans barre");
+        assert!(same_name(seen[0].hash, seen[1].hash));
+    }
+
+    #[test]
+    fn name_hash_tells_equal_length_names_apart() {
+        let hash = |name: &str, fill: f32, dx: u32| {
+            sight_boss_bars([boss_zone_at(name, fill, dx)])[0].hash
+        };
+
+        for name in [MOHG, GODRICK, MISBEGOTTEN] {
+            // Zone décalée, autre bruit, barre entamée : même nom
+            let d = distance(hash(name, 1.0, 0), hash(name, 0.4, 5));
+            assert!(d <= HASH_TOLERANCE, "{name} : {d} bits");
+        }
+        for (a, b) in [(MOHG, GODRICK), (MOHG, MISBEGOTTEN), (GODRICK, MISBEGOTTEN)] {
+            let d = distance(hash(a, 1.0, 0), hash(b, 1.0, 0));
+            assert!(d > 2 * HASH_TOLERANCE, "{a} / {b} : {d} bits");
+        }
+    }
+
+    #[test]
+    fn health_is_relative_to_the_bar_track() {
+        let mut cache = BossNameCache::new();
+
+        // Barre aperçue pour la première fois à mi-vie
+        let seen = sight_boss_bars([boss_zone(MOHG, 0.5)]);
+        cache.observe(&seen, Duration::from_secs(5));
+        assert_eq!(
+            cache.take_fight(Duration::from_secs(6)).unwrap().health,
+            vec![0.5]
+        );
+
+        // Duo : la seconde barre disparaît, la première garde ses PV réels
+        let duo = sight_boss_bars([boss_zone(MOHG, 0.8), boss_zone(GODRICK, 0.4)]);
+        cache.observe(&duo, Duration::from_secs(10));
+        let alone = sight_boss_bars([boss_zone(MOHG, 0.6)]);
+        cache.observe(&alone, Duration::from_secs(11));
+        let health = cache.take_fight(Duration::from_secs(12)).unwrap().health;
+        assert!((health[0] - 0.6).abs() < 0.01, "{health:?}");
+
+        // Arène sombre à droite de la barre : le décor ne compte pas dans la barre
+        for fill in [1.0, 0.5] {
+            let bar = find_health_bar(&dark_arena_zone(fill)).unwrap();
+            let health = bar.filled as f32 / bar.track as f32;
+            assert!((health - fill).abs() < 0.01, "{fill} : {bar:?}");
+        }
+    }
+
+    #[test]
+    fn cached_names_are_confirmed_before_use() {
+        let mut cache = BossNameCache::new();
+        let margit = 0xF0F0_F0F0_0F0F_0F0F_F0F0_F0F0_0F0F_0F0F_u128;
+        let margit_name = || vec!["Margit, the Fell Omen".to_string()];
+
+        // Début du combat : nom inconnu, lu une seule fois
+        assert_eq!(
+            cache.observe(&[bar(margit, 200)], Duration::from_secs(10)),
+            Some(NameCheck::Read)
+        );
+        assert_eq!(
+            cache.observe(&[bar(margit ^ 0b11, 24)], Duration::from_secs(11)),
+            None
+        );
+        cache.record(&[margit], NameCheck::Read, margit_name());
+
+        // Mort juste après la disparition de la barre : 12 % de PV restants
+        assert_eq!(
+            cache.take_fight(Duration::from_secs(20)),
+            Some(FightSummary {
+                names: Some(margit_name()),
+                health: vec![0.12],
+            })
+        );
+        assert_eq!(cache.take_fight(Duration::from_secs(21)), None);
+
+        // Retour au boss : trouvé en cache, mais pas attribué tant que la lecture rapide
+        // n'a pas confirmé (la mort relit alors les noms)
+        assert_eq!(
+            cache.observe(&[bar(margit, 200)], Duration::from_secs(60)),
+            Some(NameCheck::Confirm)
+        );
+        assert_eq!(
+            cache.take_fight(Duration::from_secs(61)).unwrap().names,
+            None
+        );
+
+        // Lecture rapide imparfaite mais cohérente : le nom du cache est gardé
+        cache.observe(&[bar(margit, 200)], Duration::from_secs(70));
+        cache.record(
+            &[margit],
+            NameCheck::Confirm,
+            vec!["Margit, the Fel Omen".to_string()],
+        );
+        assert_eq!(
+            cache.take_fight(Duration::from_secs(71)).unwrap().names,
+            Some(margit_name())
+        );
+
+        // Autre boss à l'empreinte proche : le cache est démenti puis le nom relu en entier
+        let morgott = margit ^ 0b1;
+        assert_eq!(
+            cache.observe(&[bar(morgott, 200)], Duration::from_secs(80)),
+            Some(NameCheck::Confirm)
+        );
+        cache.record(
+            &[morgott],
+            NameCheck::Confirm,
+            vec!["Morgott, the Omen King".to_string()],
+        );
+        assert_eq!(
+            cache.observe(&[bar(morgott, 150)], Duration::from_secs(81)),
+            Some(NameCheck::Read)
+        );
+        cache.record(
+            &[morgott],
+            NameCheck::Read,
+            vec!["Morgott, the Omen King".to_string()],
+        );
+        assert_eq!(
+            cache.take_fight(Duration::from_secs(82)).unwrap().names,
+            Some(vec!["Morgott, the Omen King".to_string()])
+        );
+
+        // Mort bien après la fin du combat : rien à attribuer
+        cache.observe(&[bar(morgott, 200)], Duration::from_secs(90));
+        assert_eq!(cache.take_fight(Duration::from_secs(150)), None);
+    }
+}
@@ -0,0 +1,216 @@
+// src/utils/export.rs
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Image par seconde utilisée pour les timecodes EDL
+pub const EDL_FPS: u32 = 30;
+/// YouTube ignore les chapitres de moins de 10 secondes
+const YOUTUBE_MIN_CHAPTER: Duration = Duration::from_secs(10);
+
+/// Une mort horodatée relativement au début de la session ou de la vidéo
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeathMarker {
+    pub timestamp: Duration,
+    pub bosses: Vec<String>,
+}
+
+impl DeathMarker {
+    pub fn new(timestamp: Duration, bosses: Vec<String>) -> Self {
+        Self { timestamp, bosses }
+    }
+
+    /// Libellé affiché dans les chapitres et marqueurs : "Mort #3 - Margit"
+    pub fn label(&self, index: usize) -> String {
+        if self.bosses.is_empty() {
+            format!("Mort #{}", index + 1)
+        } else {
+            format!("Mort #{} - {}", index + 1, self.bosses.join(" - "))
+        }
+    }
+}
+
+/// Chapitres YouTube (à coller dans la description)
+///
+/// Le premier chapitre doit commencer à 0:00 et chaque chapitre durer au moins 10 s,
+/// les morts trop rapprochées sont donc regroupées dans le chapitre précédent.
+pub fn to_youtube_chapters(markers: &[DeathMarker]) -> String {
+    let with_hours = markers
+        .last()
+        .is_some_and(|m| m.timestamp >= Duration::from_secs(3600));
+
+    let mut lines = vec![format!(
+        "{} Début",
+        youtube_timestamp(Duration::ZERO, with_hours)
+    )];
+    let mut last_start = Duration::ZERO;
+
+    for (index, marker) in markers.iter().enumerate() {
+        if marker.timestamp.saturating_sub(last_start) < YOUTUBE_MIN_CHAPTER {
+            continue;
+        }
+        lines.push(format!(
+            "{} {}",
+            youtube_timestamp(marker.timestamp, with_hours),
+            marker.label(index)
+        ));
+        last_start = marker.timestamp;
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Liste de montage CMX3600 avec un marqueur (`* LOC:`) par mort
+pub fn to_edl(markers: &[DeathMarker], title: &str) -> String {
+    let mut edl = format!("TITLE: {}\nFCM: NON-DROP FRAME\n\n", title);
+
+    for (index, marker) in markers.iter().enumerate() {
+        let frame = to_frames(marker.timestamp);
+        let start = frames_timecode(frame);
+        let end = frames_timecode(frame + 1);
+        edl.push_str(&format!(
+            "{:03}  AX       V     C        {} {} {} {}\n",
+            index + 1,
+            start,
+            end,
+            start,
+            end
+        ));
+        edl.push_str(&format!(
+            "* LOC: {} RED     {}\n\n",
+            start,
+            marker.label(index)
+        ));
+    }
+
+    edl
+}
+
+/// CSV des timecodes : index, timecode, secondes, boss
+pub fn to_csv(markers: &[DeathMarker]) -> String {
+    let mut csv = String::from("index,timecode,seconds,bosses\n");
+
+    for (index, marker) in markers.iter().enumerate() {
+        csv.push_str(&format!(
+            "{},{},{:.3},{}\n",
+            index + 1,
+            edl_timecode(marker.timestamp),
+            marker.timestamp.as_secs_f64(),
+            csv_field(&marker.bosses.join(" - "))
+        ));
+    }
+
+    csv
+}
+
+/// Écrire les trois exports à côté de `base` : `<base>_chapters.txt`, `<base>.edl`, `<base>_deaths.csv`
+pub fn export_all(markers: &[DeathMarker], base: &Path) -> Result<Vec<PathBuf>, String> {
+    let stem = base
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "deaths".to_string());
+    let dir = base.parent().unwrap_or(Path::new("."));
+
+    let files = [
+        (
+            dir.join(format!("{}_chapters.txt", stem)),
+            to_youtube_chapters(markers),
+        ),
+        (dir.join(format!("{}.edl", stem)), to_edl(markers, &stem)),
+        (dir.join(format!("{}_deaths.csv", stem)), to_csv(markers)),
+    ];
+
+    let mut written = Vec::new();
+    for (path, content) in files {
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Erreur écriture '{}': {}", path.display(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn youtube_timestamp(timestamp: Duration, with_hours: bool) -> String {
+    let secs = timestamp.as_secs();
+    if with_hours {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs / 60) % 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+fn to_frames(timestamp: Duration) -> u64 {
+    timestamp.as_millis() as u64 * EDL_FPS as u64 / 1000
+}
+
+/// Timecode `HH:MM:SS:FF` à partir d'un nombre d'images
+fn frames_timecode(frames: u64) -> String {
+    let fps = EDL_FPS as u64;
+    let secs = frames / fps;
+    format!(
+        "{:02}:{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60,
+        frames % fps
+    )
+}
+
+fn edl_timecode(timestamp: Duration) -> String {
+    frames_timecode(to_frames(timestamp))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markers() -> Vec<DeathMarker> {
+        vec![
+            DeathMarker::new(Duration::from_secs(65), vec!["Margit".to_string()]),
+            DeathMarker::new(Duration::from_secs(70), vec![]),
+            DeathMarker::new(
+                Duration::from_millis(600_500),
+                vec!["Godrick, le Greffé".to_string()],
+            ),
+        ]
+    }
+
+    #[test]
+    fn youtube_chapters_start_at_zero_and_skip_short_chapters() {
+        let chapters = to_youtube_chapters(&markers());
+
+        assert_eq!(
+            chapters,
+            "00:00 Début\n01:05 Mort #1 - Margit\n10:00 Mort #3 - Godrick, le Greffé\n"
+        );
+    }
+
+    #[test]
+    fn edl_has_one_event_and_marker_per_death() {
+        let edl = to_edl(&markers(), "run");
+
+        assert!(edl.starts_with("TITLE: run\nFCM: NON-DROP FRAME\n"));
+        assert!(edl.contains("001  AX       V     C        00:01:05:00 00:01:05:01"));
+        assert!(edl.contains("* LOC: 00:10:00:15 RED     Mort #3 - Godrick, le Greffé"));
+        assert_eq!(edl.matches("* LOC:").count(), 3);
+    }
+
+    #[test]
+    fn csv_quotes_boss_names_with_commas() {
+        let csv = to_csv(&markers());
+
+        assert_eq!(
+            csv,
+            "index,timecode,seconds,bosses\n\
+             1,00:01:05:00,65.000,Margit\n\
+             2,00:01:10:00,70.000,\n\
+             3,00:10:00:15,600.500,\"Godrick, le Greffé\"\n"
+        );
+    }
+}
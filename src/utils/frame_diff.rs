@@ -0,0 +1,127 @@
+// src/utils/frame_diff.rs
+//
+// La plupart des scans tombent sur une zone de mort identique à la précédente.
+// Une miniature en niveaux de gris suffit pour le voir : si rien n'a changé depuis
+// la dernière image sans mort, le pré-filtre couleur et l'OCR sont inutiles.
+// Quand l'image reste figée (pause, menu, jeu inactif), le scan ralentit.
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+use std::time::Duration;
+
+const THUMB_WIDTH: u32 = 32;
+const THUMB_HEIGHT: u32 = 8;
+/// Écart moyen (niveaux de gris 0-255) en dessous duquel la zone n'a pas changé
+const CHANGE_THRESHOLD: f32 = 3.0;
+/// Scans inchangés d'affilée avant de ralentir (puis à chaque palier suivant)
+const IDLE_STEP: u32 = 6;
+/// Ralentissement maximal par rapport à la cadence normale
+const IDLE_MAX_FACTOR: u32 = 4;
+
+/// Miniature en niveaux de gris de la zone
+pub fn thumbnail(zone: &DynamicImage) -> Vec<u8> {
+    zone.resize_exact(THUMB_WIDTH, THUMB_HEIGHT, FilterType::Triangle)
+        .to_luma8()
+        .into_raw()
+}
+
+fn mean_abs_diff(a: &[u8], b: &[u8]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return f32::MAX;
+    }
+    let total: u32 = a.iter().zip(b).map(|(x, y)| x.abs_diff(*y) as u32).sum();
+    total as f32 / a.len() as f32
+}
+
+#[derive(Debug, Default)]
+pub struct FrameDiff {
+    /// Miniature de la dernière zone analysée sans mort
+    last_negative: Option<Vec<u8>>,
+    unchanged_scans: u32,
+}
+
+impl FrameDiff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Vrai si la zone n'a pas changé depuis la dernière image sans mort
+    pub fn is_unchanged(&mut self, thumb: &[u8]) -> bool {
+        let unchanged = self
+            .last_negative
+            .as_deref()
+            .is_some_and(|last| mean_abs_diff(last, thumb) < CHANGE_THRESHOLD);
+        self.unchanged_scans = if unchanged {
+            self.unchanged_scans + 1
+        } else {
+            0
+        };
+        unchanged
+    }
+
+    /// Zone analysée sans mort : nouvelle référence
+    pub fn record_negative(&mut self, thumb: Vec<u8>) {
+        self.last_negative = Some(thumb);
+    }
+
+    /// Après une mort : la prochaine image est toujours analysée
+    pub fn reset(&mut self) {
+        self.last_negative = None;
+        self.unchanged_scans = 0;
+    }
+
+    /// Intervalle entre deux scans : `base`, allongé par paliers tant que rien ne bouge
+    pub fn scan_interval(&self, base: Duration) -> Duration {
+        let factor = (1 + self.unchanged_scans / IDLE_STEP).min(IDLE_MAX_FACTOR);
+        base * factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    fn zone(offset: u8) -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_fn(300, 60, |x, y| {
+            Luma([(((x + y) % 200) as u8).saturating_add(offset)])
+        }))
+    }
+
+    #[test]
+    fn only_changed_zones_are_analysed() {
+        let mut diff = FrameDiff::new();
+        let first = thumbnail(&zone(0));
+        assert!(!diff.is_unchanged(&first), "pas encore de référence");
+        diff.record_negative(first);
+
+        // Bruit de compression : identique ; bannière de mort : changement
+        assert!(diff.is_unchanged(&thumbnail(&zone(1))));
+        let banner = DynamicImage::ImageLuma8(GrayImage::from_pixel(300, 60, Luma([220])));
+        assert!(!diff.is_unchanged(&thumbnail(&banner)));
+
+        diff.reset();
+        assert!(!diff.is_unchanged(&thumbnail(&zone(0))));
+    }
+
+    #[test]
+    fn scan_slows_down_while_idle_and_recovers_on_change() {
+        let base = Duration::from_millis(500);
+        let mut diff = FrameDiff::new();
+        let still = thumbnail(&zone(0));
+        diff.record_negative(still.clone());
+
+        for _ in 0..IDLE_STEP {
+            diff.is_unchanged(&still);
+        }
+        assert_eq!(diff.scan_interval(base), base * 2);
+        for _ in 0..100 {
+            diff.is_unchanged(&still);
+        }
+        assert_eq!(diff.scan_interval(base), base * IDLE_MAX_FACTOR);
+
+        let moved = thumbnail(&zone(60));
+        assert!(!diff.is_unchanged(&moved));
+        assert_eq!(diff.scan_interval(base), base);
+    }
+}
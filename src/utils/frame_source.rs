@@ -0,0 +1,680 @@
+// src/utils/frame_source.rs
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::structs::settings::capture_source::CaptureSource;
+use crate::structs::settings::crop_position::{ContentArea, CropPosition};
+use crate::utils::screen_capture::{
+    WindowFrame, capture_screen_region, capture_screen_rgba, capture_window_frame, find_window,
+};
+use tracing::{debug, warn};
+use xcap::Window;
+
+/// Images par seconde extraites des vidéos (même cadence que le scan live)
+pub const VIDEO_SAMPLE_FPS: u32 = 2;
+/// Mesures identiques des bandes noires nécessaires avant d'adopter une zone utile
+const CONTENT_STABLE_MEASURES: u32 = 3;
+/// Zone utile remesurée régulièrement (changement de résolution ou de mode d'affichage)
+const CONTENT_REMEASURE_INTERVAL: Duration = Duration::from_secs(10);
+/// Zone utile pas encore stable (scène sombre, changement en cours) : mesures espacées d'autant
+const CONTENT_RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Image lue à chaque scan : la zone de mort, et l'image entière quand la source l'a déjà
+pub struct ScanFrame {
+    pub zone: DynamicImage,
+    pub full: Option<DynamicImage>,
+}
+
+/// Source d'images consommée par le worker OCR
+///
+/// Les appels sont bloquants : le worker les exécute dans `spawn_blocking`.
+pub trait FrameSource: Send {
+    /// Prochaine image, `Ok(None)` si aucune nouvelle image n'est disponible pour l'instant
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String>;
+
+    /// Prochaine image réduite à `zone`. Les sources live ne capturent que la zone ;
+    /// les sources hors-ligne décodent de toute façon l'image entière et la gardent.
+    fn next_zone(&mut self, zone: &CropPosition) -> Result<Option<ScanFrame>, String> {
+        Ok(self.next_frame()?.map(|frame| ScanFrame {
+            zone: zone.crop_image(&frame, self.content_area(&frame)),
+            full: Some(frame),
+        }))
+    }
+
+    /// Zone utile (sans bandes noires) d'une image entière rendue par cette source.
+    /// Par défaut mesurée sur l'image seule ; les flux continus gardent une zone validée.
+    fn content_area(&self, frame: &DynamicImage) -> ContentArea {
+        ContentArea::of(frame)
+    }
+
+    /// Vrai quand la source est terminée (fin de vidéo, stdin fermé)
+    fn is_exhausted(&self) -> bool {
+        false
+    }
+
+    /// Source temps réel : le worker cadence les scans lui-même.
+    /// Les sources hors-ligne sont lues aussi vite que possible.
+    fn is_live(&self) -> bool {
+        true
+    }
+
+    /// Position de la dernière image dans le flux (vidéo), `None` pour les sources live
+    fn position(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl CaptureSource {
+    /// Ouvrir la source d'images correspondante
+    pub fn open(&self) -> Result<Box<dyn FrameSource>, String> {
+        match self {
+            CaptureSource::Monitor(index) => Ok(Box::new(MonitorSource::new(*index))),
+            CaptureSource::Window(name) => Ok(Box::new(WindowSource::new(name.clone()))),
+            CaptureSource::ImageFolder(dir) => Ok(Box::new(ImageFolderSource::new(dir.clone())?)),
+            CaptureSource::VideoFile(path) => Ok(Box::new(VideoFileSource::new(path)?)),
+            CaptureSource::Stdin { width, height } => {
+                Ok(Box::new(StdinSource::new(*width, *height)))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ZONE UTILE (BANDES NOIRES)
+// ============================================================================
+
+/// Zone utile d'un flux d'images, validée sur plusieurs captures complètes
+///
+/// Une mesure isolée ne suffit pas : un écran de chargement ou un menu noir ferait croire
+/// à des bandes de 30 % de chaque côté. Les images trop sombres sont ignorées, une zone
+/// n'est adoptée qu'après plusieurs mesures identiques, puis remesurée régulièrement.
+/// Les mesures (captures complètes) sont espacées même quand elles échouent : entre deux,
+/// la dernière zone validée sert, ou l'image entière.
+#[derive(Debug, Clone, Default)]
+pub struct ContentTracker {
+    size: (u32, u32),
+    validated: Option<ContentArea>,
+    /// Dernière zone mesurée et nombre de mesures identiques consécutives
+    candidate: Option<(ContentArea, u32)>,
+    /// Dernière capture complète mesurée, réussie ou non
+    last_attempt: Option<Instant>,
+}
+
+impl ContentTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Une capture complète est-elle nécessaire pour (re)mesurer la zone utile ?
+    pub fn needs_measure(&self) -> bool {
+        self.measure_due(Instant::now())
+    }
+
+    fn measure_due(&self, now: Instant) -> bool {
+        let Some(last) = self.last_attempt else {
+            return true;
+        };
+        let settled = self.validated.is_some()
+            && self
+                .candidate
+                .is_none_or(|(area, _)| Some(area) == self.validated);
+        let interval = if settled {
+            CONTENT_REMEASURE_INTERVAL
+        } else {
+            CONTENT_RETRY_INTERVAL
+        };
+        now.saturating_duration_since(last) >= interval
+    }
+
+    /// Mesurer sur une image entière ; renvoie la zone utile à utiliser pour cette image
+    pub fn observe<I: GenericImageView<Pixel = Rgba<u8>>>(&mut self, image: &I) -> ContentArea {
+        let size = image.dimensions();
+        if size != self.size {
+            *self = Self {
+                size,
+                ..Self::default()
+            };
+        }
+
+        self.last_attempt = Some(Instant::now());
+        if let Some(area) = ContentArea::detect(image) {
+            let count = match self.candidate {
+                Some((candidate, count)) if candidate == area => count + 1,
+                _ => 1,
+            };
+            self.candidate = Some((area, count));
+            if count >= CONTENT_STABLE_MEASURES {
+                self.validated = Some(area);
+            }
+        }
+        self.area()
+    }
+
+    /// Zone validée, l'image entière tant qu'aucune mesure n'est stable
+    pub fn area(&self) -> ContentArea {
+        self.validated
+            .unwrap_or(ContentArea::full(self.size.0, self.size.1))
+    }
+
+    /// Zone validée pour une image de cette taille, sinon mesure sur l'image seule
+    pub fn area_for(&self, frame: &DynamicImage) -> ContentArea {
+        if frame.dimensions() == self.size {
+            self.area()
+        } else {
+            ContentArea::of(frame)
+        }
+    }
+
+    /// Taille des captures complètes mesurées
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+}
+
+// ============================================================================
+// ÉCRAN / FENÊTRE
+// ============================================================================
+
+pub struct MonitorSource {
+    index: i8,
+    /// Zone utile (bandes noires) validée sur les captures complètes
+    content: ContentTracker,
+    /// Passe à faux si le backend ne sait pas capturer une zone
+    region_capture: bool,
+}
+
+impl MonitorSource {
+    pub fn new(index: i8) -> Self {
+        Self {
+            index,
+            content: ContentTracker::new(),
+            region_capture: true,
+        }
+    }
+
+    /// Capture complète : mesure de la zone utile puis crop avant conversion
+    fn full_zone(&mut self, zone: &CropPosition) -> Result<Option<ScanFrame>, String> {
+        let rgba = capture_screen_rgba(self.index)?;
+        let content = self.content.observe(&rgba);
+        Ok(Some(ScanFrame {
+            zone: DynamicImage::ImageRgba8(zone.crop_rgba(&rgba, content)),
+            full: Some(DynamicImage::ImageRgba8(rgba)),
+        }))
+    }
+}
+
+impl FrameSource for MonitorSource {
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String> {
+        let rgba = capture_screen_rgba(self.index)?;
+        self.content.observe(&rgba);
+        Ok(Some(DynamicImage::ImageRgba8(rgba)))
+    }
+
+    fn next_zone(&mut self, zone: &CropPosition) -> Result<Option<ScanFrame>, String> {
+        // Mesure de la zone utile due : écran entier (sinon dernière zone validée)
+        if self.content.needs_measure() {
+            return self.full_zone(zone);
+        }
+
+        if self.region_capture {
+            let region = zone.to_pixels_in(zone.reference_area(self.content.area()));
+            match capture_screen_region(self.index, region, self.content.size()) {
+                Ok(image) => {
+                    return Ok(Some(ScanFrame {
+                        zone: image,
+                        full: None,
+                    }));
+                }
+                Err(e) => {
+                    warn!("⚠️ Capture de zone indisponible, capture complète : {}", e);
+                    self.region_capture = false;
+                }
+            }
+        }
+
+        // Repli : capture complète, cropée avant conversion
+        self.full_zone(zone)
+    }
+
+    fn content_area(&self, frame: &DynamicImage) -> ContentArea {
+        self.content.area_for(frame)
+    }
+}
+
+pub struct WindowSource {
+    name: String,
+    /// Fenêtre trouvée une fois, recherchée à nouveau seulement si la capture échoue
+    window: Option<Window>,
+    /// Zone utile mesurée dans la zone client
+    content: ContentTracker,
+    /// Taille de la dernière capture et origine de sa zone client
+    client: Option<((u32, u32), (u32, u32))>,
+}
+
+impl WindowSource {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            window: None,
+            content: ContentTracker::new(),
+            client: None,
+        }
+    }
+
+    fn capture(&mut self) -> Result<WindowFrame, String> {
+        if let Some(window) = self.window.take() {
+            match capture_window_frame(&window) {
+                Ok(frame) => {
+                    self.window = Some(window);
+                    return Ok(frame);
+                }
+                Err(e) => debug!("Fenêtre '{}' perdue, nouvelle recherche : {}", self.name, e),
+            }
+        }
+        let window = find_window(&self.name)?;
+        let frame = capture_window_frame(&window)?;
+        self.window = Some(window);
+        Ok(frame)
+    }
+
+    /// Zone utile de la capture (décalée de la barre de titre et des bordures)
+    fn observe(&mut self, frame: &WindowFrame) -> ContentArea {
+        let (x, y, width, height) = frame.client;
+        let area = if self.content.needs_measure() || self.content.size() != (width, height) {
+            self.content.observe(&*image::imageops::crop_imm(
+                &frame.image,
+                x,
+                y,
+                width,
+                height,
+            ))
+        } else {
+            self.content.area()
+        };
+        self.client = Some((frame.image.dimensions(), (x, y)));
+        Self::in_capture(area, (x, y))
+    }
+
+    /// Zone utile mesurée dans la zone client, en pixels de la capture entière
+    fn in_capture(area: ContentArea, (x, y): (u32, u32)) -> ContentArea {
+        ContentArea {
+            x: area.x + x,
+            y: area.y + y,
+            ..area
+        }
+    }
+}
+
+impl FrameSource for WindowSource {
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String> {
+        let frame = self.capture()?;
+        self.observe(&frame);
+        Ok(Some(DynamicImage::ImageRgba8(frame.image)))
+    }
+
+    // Pas de capture partielle de fenêtre : on crope le buffer brut, gardé pour les
+    // barres de boss et l'écran de mort
+    fn next_zone(&mut self, zone: &CropPosition) -> Result<Option<ScanFrame>, String> {
+        let frame = self.capture()?;
+        let content = self.observe(&frame);
+        Ok(Some(ScanFrame {
+            zone: DynamicImage::ImageRgba8(zone.crop_rgba(&frame.image, content)),
+            full: Some(DynamicImage::ImageRgba8(frame.image)),
+        }))
+    }
+
+    fn content_area(&self, frame: &DynamicImage) -> ContentArea {
+        match self.client {
+            Some((size, origin)) if frame.dimensions() == size => {
+                Self::in_capture(self.content.area(), origin)
+            }
+            _ => ContentArea::of(frame),
+        }
+    }
+}
+
+// ============================================================================
+// DOSSIER D'IMAGES SURVEILLÉ
+// ============================================================================
+
+pub struct ImageFolderSource {
+    dir: PathBuf,
+    seen: HashSet<PathBuf>,
+}
+
+impl ImageFolderSource {
+    pub fn new(dir: PathBuf) -> Result<Self, String> {
+        if !dir.is_dir() {
+            return Err(format!("Dossier '{}' introuvable", dir.display()));
+        }
+        Ok(Self {
+            dir,
+            seen: HashSet::new(),
+        })
+    }
+
+    fn is_image(path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| {
+                matches!(
+                    ext.to_lowercase().as_str(),
+                    "png" | "jpg" | "jpeg" | "bmp" | "webp"
+                )
+            })
+            .unwrap_or(false)
+    }
+}
+
+impl FrameSource for ImageFolderSource {
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String> {
+        let entries = std::fs::read_dir(&self.dir)
+            .map_err(|e| format!("Erreur lecture dossier '{}': {}", self.dir.display(), e))?;
+
+        // La plus ancienne image pas encore analysée
+        let next = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| Self::is_image(path) && !self.seen.contains(path))
+            .min_by_key(|path| {
+                let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+                (modified, path.clone())
+            });
+
+        let Some(path) = next else {
+            return Ok(None);
+        };
+        self.seen.insert(path.clone());
+
+        image::open(&path)
+            .map(Some)
+            .map_err(|e| format!("Erreur lecture image '{}': {}", path.display(), e))
+    }
+}
+
+// ============================================================================
+// FICHIER VIDÉO (ffmpeg)
+// ============================================================================
+
+pub struct VideoFileSource {
+    child: Child,
+    stdout: ChildStdout,
+    width: u32,
+    height: u32,
+    frames_read: u64,
+    exhausted: bool,
+    content: ContentTracker,
+}
+
+impl VideoFileSource {
+    pub fn new(path: &Path) -> Result<Self, String> {
+        let (width, height) = probe_video_size(path)?;
+
+        let mut child = Command::new("ffmpeg")
+            .args(["-v", "error", "-nostdin", "-i"])
+            .arg(path)
+            .args(["-vf", &format!("fps={}", VIDEO_SAMPLE_FPS)])
+            .args(["-f", "rawvideo", "-pix_fmt", "rgba", "-"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Impossible de lancer ffmpeg: {}", e))?;
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or("Sortie ffmpeg indisponible".to_string())?;
+
+        Ok(Self {
+            child,
+            stdout,
+            width,
+            height,
+            frames_read: 0,
+            exhausted: false,
+            content: ContentTracker::new(),
+        })
+    }
+}
+
+impl FrameSource for VideoFileSource {
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String> {
+        if self.exhausted {
+            return Ok(None);
+        }
+        match read_raw_frame(&mut self.stdout, self.width, self.height)? {
+            Some(image) => {
+                self.frames_read += 1;
+                self.content.observe(&image);
+                Ok(Some(image))
+            }
+            None => {
+                self.exhausted = true;
+                Ok(None)
+            }
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    fn is_live(&self) -> bool {
+        false
+    }
+
+    fn content_area(&self, frame: &DynamicImage) -> ContentArea {
+        self.content.area_for(frame)
+    }
+
+    fn position(&self) -> Option<Duration> {
+        let frame_index = self.frames_read.saturating_sub(1);
+        Some(Duration::from_millis(
+            frame_index * 1000 / VIDEO_SAMPLE_FPS as u64,
+        ))
+    }
+}
+
+impl Drop for VideoFileSource {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Dimensions de la première piste vidéo via ffprobe
+fn probe_video_size(path: &Path) -> Result<(u32, u32), String> {
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-select_streams",
+            "v:0",
+            "-show_entries",
+            "stream=width,height",
+            "-of",
+            "csv=p=0:s=x",
+        ])
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Impossible de lancer ffprobe: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "ffprobe a échoué sur '{}': {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    crate::structs::settings::capture_source::parse_frame_size(stdout.trim())
+}
+
+// ============================================================================
+// ENTRÉE STANDARD (images RGBA brutes)
+// ============================================================================
+
+pub struct StdinSource {
+    width: u32,
+    height: u32,
+    exhausted: bool,
+    content: ContentTracker,
+}
+
+impl StdinSource {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            exhausted: false,
+            content: ContentTracker::new(),
+        }
+    }
+}
+
+impl FrameSource for StdinSource {
+    fn next_frame(&mut self) -> Result<Option<DynamicImage>, String> {
+        if self.exhausted {
+            return Ok(None);
+        }
+        let mut stdin = std::io::stdin().lock();
+        let frame = read_raw_frame(&mut stdin, self.width, self.height)?;
+        match &frame {
+            Some(image) => {
+                self.content.observe(image);
+            }
+            None => self.exhausted = true,
+        }
+        Ok(frame)
+    }
+
+    fn content_area(&self, frame: &DynamicImage) -> ContentArea {
+        self.content.area_for(frame)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    fn is_live(&self) -> bool {
+        false
+    }
+}
+
+/// Lire une image RGBA brute, `Ok(None)` en fin de flux
+fn read_raw_frame(
+    reader: &mut impl Read,
+    width: u32,
+    height: u32,
+) -> Result<Option<DynamicImage>, String> {
+    let mut buffer = vec![0u8; width as usize * height as usize * 4];
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(format!("Erreur lecture image brute: {}", e)),
+        }
+    }
+
+    if filled == 0 {
+        return Ok(None);
+    }
+    if filled < buffer.len() {
+        return Err(format!(
+            "Image brute incomplète ({} / {} octets)",
+            filled,
+            buffer.len()
+        ));
+    }
+
+    let rgba = RgbaImage::from_raw(width, height, buffer).ok_or("Buffer RGBA invalide")?;
+    Ok(Some(DynamicImage::ImageRgba8(rgba)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_raw_frame_reads_exact_frames() {
+        let data = vec![255u8; 2 * 2 * 4 * 2];
+        let mut reader = data.as_slice();
+
+        assert!(read_raw_frame(&mut reader, 2, 2).unwrap().is_some());
+        assert!(read_raw_frame(&mut reader, 2, 2).unwrap().is_some());
+        assert!(read_raw_frame(&mut reader, 2, 2).unwrap().is_none());
+    }
+
+    /// 16:9 pillarboxé dans du 21:9 (`lit` : contenu éclairé ou écran de chargement noir)
+    fn ultrawide_frame(lit: bool) -> RgbaImage {
+        let colour = if lit {
+            [90, 80, 70, 255]
+        } else {
+            [0, 0, 0, 255]
+        };
+        RgbaImage::from_fn(2560, 1080, |x, _| {
+            if (320..2240).contains(&x) {
+                image::Rgba(colour)
+            } else {
+                image::Rgba([0, 0, 0, 255])
+            }
+        })
+    }
+
+    #[test]
+    fn content_area_is_adopted_only_once_stable() {
+        let mut tracker = ContentTracker::new();
+        let pillarboxed = ContentArea {
+            x: 320,
+            y: 0,
+            width: 1920,
+            height: 1080,
+        };
+
+        // Écran de chargement : ignoré, image entière en attendant
+        assert_eq!(
+            tracker.observe(&ultrawide_frame(false)),
+            ContentArea::full(2560, 1080)
+        );
+        // Mesure ratée : pas de nouvelle capture complète à chaque scan
+        assert!(!tracker.needs_measure());
+        assert!(tracker.measure_due(Instant::now() + CONTENT_RETRY_INTERVAL));
+
+        for _ in 1..CONTENT_STABLE_MEASURES {
+            assert_eq!(
+                tracker.observe(&ultrawide_frame(true)),
+                ContentArea::full(2560, 1080)
+            );
+        }
+        assert_eq!(tracker.observe(&ultrawide_frame(true)), pillarboxed);
+        assert!(!tracker.measure_due(Instant::now() + CONTENT_RETRY_INTERVAL));
+        assert!(tracker.measure_due(Instant::now() + CONTENT_REMEASURE_INTERVAL));
+
+        // Un menu noir ou une image isolée différente ne remet pas la zone en cause
+        assert_eq!(tracker.observe(&ultrawide_frame(false)), pillarboxed);
+        let fullscreen = RgbaImage::from_pixel(2560, 1080, image::Rgba([90, 80, 70, 255]));
+        assert_eq!(tracker.observe(&fullscreen), pillarboxed);
+        assert!(!tracker.needs_measure());
+        assert!(tracker.measure_due(Instant::now() + CONTENT_RETRY_INTERVAL));
+
+        // Changement de résolution : tout est remesuré
+        let resized = RgbaImage::from_pixel(1920, 1080, image::Rgba([90, 80, 70, 255]));
+        assert_eq!(tracker.observe(&resized), ContentArea::full(1920, 1080));
+    }
+
+    #[test]
+    fn read_raw_frame_rejects_truncated_frame() {
+        let data = vec![0u8; 10];
+        let mut reader = data.as_slice();
+
+        assert!(read_raw_frame(&mut reader, 2, 2).is_err());
+    }
+}
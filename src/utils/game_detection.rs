@@ -0,0 +1,129 @@
+// game_detection.rs - Détection du jeu lancé à partir des processus en cours
+//
+// Sous Linux (Proton / Wine) le nom du processus est celui de l'exécutable Windows,
+// tronqué à 15 caractères : on compare aussi le chemin de l'exécutable et la ligne de commande.
+// Un émulateur ne désigne pas un jeu : l'identifiant du jeu doit apparaître dans sa ligne
+// de commande ou le titre de sa fenêtre.
+
+use crate::structs::settings::game::{ALL_GAMES, Game};
+use std::ffi::OsStr;
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use xcap::Window;
+
+/// Intervalle entre deux scans des processus
+pub const GAME_SCAN_INTERVAL_SECS: u64 = 5;
+
+/// Jeu correspondant à un nom d'exécutable ou à un chemin (séparateurs `/` ou `\`)
+pub fn game_from_process(name: &str) -> Option<Game> {
+    let file_name = name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(name)
+        .trim()
+        .to_lowercase();
+    if file_name.is_empty() {
+        return None;
+    }
+
+    ALL_GAMES.iter().copied().find(|game| {
+        game.process_names().iter().any(|process| {
+            let process = process.to_lowercase();
+            // Nom de processus Linux tronqué à 15 caractères
+            file_name == process || (file_name.len() == 15 && process.starts_with(&file_name))
+        })
+    })
+}
+
+/// Le texte (ligne de commande, titre de fenêtre) contient-il un identifiant du jeu ?
+fn mentions_title_id(game: Game, text: &str) -> bool {
+    let text = text.to_uppercase();
+    game.title_ids().iter().any(|id| text.contains(id))
+}
+
+/// Jeu lancé par un processus : par son nom, et pour un émulateur par l'identifiant du jeu
+fn game_of_process(pid: Pid, process: &Process) -> Option<Game> {
+    let exe = process.exe().map(|p| p.as_os_str());
+    let first_arg = process.cmd().first().map(|a| a.as_os_str());
+    let game = [Some(process.name()), exe, first_arg]
+        .into_iter()
+        .flatten()
+        .map(OsStr::to_string_lossy)
+        .find_map(|name| game_from_process(&name))?;
+    if game.title_ids().is_empty() {
+        return Some(game);
+    }
+
+    let command_line = process
+        .cmd()
+        .iter()
+        .map(|arg| arg.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if mentions_title_id(game, &command_line) {
+        return Some(game);
+    }
+    // Lancé depuis l'interface de l'émulateur : l'identifiant est dans le titre de la fenêtre
+    let windows = Window::all().unwrap_or_default();
+    windows
+        .iter()
+        .filter(|w| w.pid().is_ok_and(|window_pid| window_pid == pid.as_u32()))
+        .any(|w| mentions_title_id(game, &w.title().unwrap_or_default()))
+        .then_some(game)
+}
+
+/// Scanner les processus en cours (bloquant, quelques dizaines de ms)
+pub fn detect_running_game() -> Option<Game> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+
+    system
+        .processes()
+        .iter()
+        .find_map(|(pid, process)| game_of_process(*pid, process))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_from_process_handles_paths_case_and_truncation() {
+        assert_eq!(game_from_process("eldenring.exe"), Some(Game::EldenRing));
+        assert_eq!(
+            game_from_process(r"Z:\steam\steamapps\common\ELDEN RING\Game\ELDENRING.EXE"),
+            Some(Game::EldenRing)
+        );
+        assert_eq!(game_from_process("DarkSoulsRemast"), Some(Game::DarkSouls));
+        assert_eq!(
+            game_from_process("/games/DARK SOULS III/Game/DarkSoulsIII.exe"),
+            Some(Game::DarkSouls3)
+        );
+        assert_eq!(game_from_process("DarkSoulsII.exe"), Some(Game::DarkSouls2));
+        assert_eq!(game_from_process("steam"), None);
+    }
+
+    #[test]
+    fn emulated_games_need_their_title_id() {
+        assert_eq!(game_from_process("shadPS4.exe"), Some(Game::Bloodborne));
+        assert!(mentions_title_id(
+            Game::Bloodborne,
+            r"shadPS4.exe D:\ps4\games\CUSA00900\eboot.bin"
+        ));
+        assert!(mentions_title_id(
+            Game::Bloodborne,
+            "shadPS4 v0.9.0 | Bloodborne cusa03173"
+        ));
+        // Autre jeu PS4 dans le même émulateur
+        assert!(!mentions_title_id(
+            Game::Bloodborne,
+            r"shadPS4.exe D:\ps4\games\CUSA00003\eboot.bin"
+        ));
+        assert!(!mentions_title_id(Game::Bloodborne, "shadPS4.exe"));
+    }
+}
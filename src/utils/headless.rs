@@ -0,0 +1,44 @@
+// src/utils/headless.rs
+use iced::futures::StreamExt;
+
+use crate::screens::components::ocr::OcrMessage;
+use crate::structs::settings::capture_source::CaptureSource;
+use crate::structs::settings::settings::Settings;
+use crate::utils::app_worker::ocr_worker;
+use tracing::info;
+
+/// Lancer le pipeline de détection sans interface (ex: `--headless video:run.mp4`)
+///
+/// Utilise le jeu et le texte de mort des paramètres enregistrés.
+pub fn run_headless(source: CaptureSource) -> Result<(), String> {
+    let settings = Settings::load();
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("Erreur runtime: {}", e))?;
+
+    runtime.block_on(async move {
+        let mut stream = Box::pin(ocr_worker(
+            source,
+            settings.get_game_config(),
+            settings.get_death_texts(),
+            settings.get_cpu_budget(),
+        ));
+        let mut deaths = 0u32;
+
+        while let Some(message) = stream.next().await {
+            match message {
+                OcrMessage::DeathDetected(_) => {
+                    deaths += 1;
+                    info!("💀 Mort #{}", deaths);
+                }
+                OcrMessage::BossesFoundOCR(_, bosses, _) if !bosses.is_empty() => {
+                    info!("⚔️ Boss : {}", bosses.join(" - "));
+                }
+                OcrMessage::ActivateOCR(false) => break,
+                _ => {}
+            }
+        }
+
+        info!("Total : {} mort(s)", deaths);
+    });
+
+    Ok(())
+}
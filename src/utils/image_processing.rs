@@ -1,138 +1,315 @@
-use image::{DynamicImage, GrayImage, ImageBuffer, Luma};
+use crate::structs::settings::color_profile::ColorProfile;
+use image::imageops::FilterType;
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, RgbaImage};
+use std::borrow::Cow;
+use tracing::{instrument, trace_span};
+
+// Les corrections (gamma, contraste) ne dépendent que de la valeur du pixel :
+// elles sont précalculées dans une table de 256 entrées puis appliquées sur place,
+// une simple indirection par octet au lieu d'un `powf` par pixel.
+
+/// Table de correspondance valeur -> valeur corrigée
+pub type Lut = [u8; 256];
+
+/// Hauteur des images envoyées à l'OCR pour la détection de mort
+const DEATH_TARGET_HEIGHT: u32 = 120;
+/// Agrandissement des barres de boss avant OCR
+pub const BOSS_UPSCALE: u32 = 4;
+/// Catmull-Rom garde des bords nets pour l'OCR et coûte bien moins que Lanczos3
+const BOSS_FILTER: FilterType = FilterType::CatmullRom;
+
+fn build_lut(f: impl Fn(f32) -> f32) -> Lut {
+    let mut lut = [0u8; 256];
+    for (value, entry) in lut.iter_mut().enumerate() {
+        *entry = f(value as f32).clamp(0.0, 255.0) as u8;
+    }
+    lut
+}
+
+pub fn gamma_lut(gamma: f32) -> Lut {
+    build_lut(|v| (v / 255.0).powf(gamma) * 255.0)
+}
+
+pub fn contrast_lut(factor: f32) -> Lut {
+    build_lut(|v| (v - 128.0) * factor + 128.0)
+}
+
+/// Gamma puis contraste autour de 0.5, sans arrondi intermédiaire
+pub fn gamma_contrast_lut(gamma: f32, contrast: f32) -> Lut {
+    build_lut(|v| (((v / 255.0).powf(gamma) - 0.5) * contrast + 0.5).clamp(0.0, 1.0) * 255.0)
+}
+
+/// Enchaîner deux tables : `first` puis `then`
+pub fn compose_luts(first: &Lut, then: &Lut) -> Lut {
+    let mut lut = [0u8; 256];
+    for (entry, value) in lut.iter_mut().zip(first.iter()) {
+        *entry = then[*value as usize];
+    }
+    lut
+}
+
+/// Appliquer une table sur place (boucle sur le buffer brut, vectorisable)
+pub fn apply_lut_in_place(img: &mut GrayImage, lut: &Lut) {
+    for value in img.iter_mut() {
+        *value = lut[*value as usize];
+    }
+}
+
+/// Emprunter le buffer RGBA sans copie quand la capture est déjà en RGBA
+fn as_rgba(image: &DynamicImage) -> Cow<'_, RgbaImage> {
+    match image.as_rgba8() {
+        Some(rgba) => Cow::Borrowed(rgba),
+        None => Cow::Owned(image.to_rgba8()),
+    }
+}
 
 //////////////////////////////////////////////////////////////////
 /////////////////////PROCESSING DEATH/////////////////////////////
 //////////////////////////////////////////////////////////////////
 pub fn adjust_gamma(img: &GrayImage, gamma: f32) -> GrayImage {
     let mut result = img.clone();
-    for pixel in result.pixels_mut() {
-        let normalized = pixel[0] as f32 / 255.0;
-        let corrected = normalized.powf(gamma);
-        pixel[0] = (corrected * 255.0) as u8;
-    }
-    result
-}
-
-pub fn increase_contrast(img: &GrayImage, factor: f32) -> GrayImage {
-    let mut result = img.clone();
-    for pixel in result.pixels_mut() {
-        let value = pixel[0] as f32;
-        let new_value = ((value - 128.0) * factor + 128.0).clamp(0.0, 255.0);
-        pixel[0] = new_value as u8;
-    }
+    apply_lut_in_place(&mut result, &gamma_lut(gamma));
     result
 }
 
-// Fonction pour détecter la présence de "texte" rouge dans une image
-pub fn has_red_text_present(image: &DynamicImage) -> bool {
-    let rgba = image.to_rgba8();
-    let mut red_pixel_count = 0;
+// Fonction pour détecter la présence de "texte" de la couleur du profil dans une image
+pub fn has_text_color_present(image: &DynamicImage, profile: &ColorProfile) -> bool {
+    let rgba = as_rgba(image);
     let total_pixels = (image.width() * image.height()) as usize;
-    for p in rgba.pixels() {
-        let r = p[0] as i32;
-        let g = p[1] as i32;
-        let b = p[2] as i32;
-
-        // Rouge dominant sombre (Elden Ring)
-        if r > 60 && r > g + 20 && r > b + 20 {
-            red_pixel_count += 1;
-        }
+    if total_pixels == 0 {
+        return false;
     }
 
-    let pct = red_pixel_count as f32 / total_pixels as f32;
+    let matching = rgba
+        .as_raw()
+        .chunks_exact(4)
+        .filter(|p| profile.matches(p[0], p[1], p[2]))
+        .count();
 
-    pct > 0.01 // 1% suffit largement
+    // Couverture en pour mille (1% suffit largement pour Elden Ring)
+    matching * 1000 > total_pixels * profile.min_coverage as usize
 }
 
-pub fn extract_red_channel(image: &DynamicImage) -> ImageBuffer<Luma<u8>, Vec<u8>> {
-    let rgba = image.to_rgba8();
-    ImageBuffer::from_fn(image.width(), image.height(), |x, y| {
-        let pixel = rgba.get_pixel(x, y);
-        Luma([pixel[0]])
-    })
+/// Part des pixels ignorés de chaque côté pour encadrer le glyphe (bruit isolé)
+const GLYPH_TRIM: f32 = 0.02;
+/// Hauteur minimale du glyphe par rapport à la zone
+const GLYPH_MIN_HEIGHT: f32 = 0.4;
+/// Largeur / hauteur acceptées : un idéogramme est à peu près carré
+const GLYPH_ASPECT: std::ops::RangeInclusive<f32> = 0.5..=2.0;
+/// Remplissage du cadre : des traits, ni quelques points ni un aplat
+const GLYPH_FILL: std::ops::RangeInclusive<f32> = 0.12..=0.75;
+/// Position horizontale du centre du glyphe dans la zone
+const GLYPH_CENTER: std::ops::RangeInclusive<f32> = 0.25..=0.75;
+
+/// Bornes [début, fin] contenant tous les pixels sauf `GLYPH_TRIM` de chaque côté
+fn trimmed_bounds(histogram: &[u32], total: u32) -> (usize, usize) {
+    let skip = (total as f32 * GLYPH_TRIM) as u32;
+    let first_over = |mut indices: Box<dyn Iterator<Item = usize> + '_>| {
+        let mut seen = 0;
+        indices
+            .find(|&i| {
+                seen += histogram[i];
+                seen > skip
+            })
+            .unwrap_or(0)
+    };
+    let start = first_over(Box::new(0..histogram.len()));
+    let end = first_over(Box::new((0..histogram.len()).rev()));
+    (start, end.max(start))
 }
-pub fn preprocess_v1_fast(red: &ImageBuffer<Luma<u8>, Vec<u8>>) -> DynamicImage {
-    use std::time::Instant;
 
-    let _t = Instant::now();
-    let _t0 = Instant::now();
-    let brightened = adjust_gamma(red, 0.4);
-    lap!(_t, "PREPROCESS |   gamma 0.4          {:>6} ms");
-    let (w, h) = brightened.dimensions();
+/// Glyphe unique (idéogramme 死 de Sekiro) : les pixels de la couleur du texte forment
+/// une tache haute, à peu près carrée et centrée, faite de traits et non d'un aplat
+pub fn has_glyph_shape(image: &DynamicImage, profile: &ColorProfile) -> bool {
+    let rgba = as_rgba(image);
+    let (width, height) = rgba.dimensions();
+    let mut columns = vec![0u32; width as usize];
+    let mut rows = vec![0u32; height as usize];
+    for (x, y, p) in rgba.enumerate_pixels() {
+        if profile.matches(p[0], p[1], p[2]) {
+            columns[x as usize] += 1;
+            rows[y as usize] += 1;
+        }
+    }
+    let total: u32 = columns.iter().sum();
+    if total == 0 {
+        return false;
+    }
 
-    const TARGET_HEIGHT: u32 = 120;
-    let scale = TARGET_HEIGHT as f32 / h as f32;
+    let (left, right) = trimmed_bounds(&columns, total);
+    let (top, bottom) = trimmed_bounds(&rows, total);
+    let box_width = (right - left + 1) as f32;
+    let box_height = (bottom - top + 1) as f32;
+    let inside: u32 = rgba
+        .enumerate_pixels()
+        .filter(|(x, y, p)| {
+            (left..=right).contains(&(*x as usize))
+                && (top..=bottom).contains(&(*y as usize))
+                && profile.matches(p[0], p[1], p[2])
+        })
+        .count() as u32;
 
-    let _t0 = Instant::now();
-    let scaled = DynamicImage::ImageLuma8(brightened).resize(
-        (w as f32 * scale) as u32,
-        TARGET_HEIGHT,
-        image::imageops::FilterType::CatmullRom,
-    );
-    lap!(_t0, "PREPROCESS |   scaled         {:>6} ms");
-    lap!(_t, "PREPROCESS |   total time");
-    scaled
+    let fill = inside as f32 / (box_width * box_height);
+    let center = (left + right) as f32 / 2.0 / width as f32;
+    box_height >= height as f32 * GLYPH_MIN_HEIGHT
+        && GLYPH_ASPECT.contains(&(box_width / box_height))
+        && GLYPH_FILL.contains(&fill)
+        && GLYPH_CENTER.contains(&center)
 }
 
-pub fn preprocess_v2_fallback(red: &ImageBuffer<Luma<u8>, Vec<u8>>) -> DynamicImage {
-    use std::time::Instant;
-
-    let _t = Instant::now();
-    let gamma = adjust_gamma(red, 0.3);
-    lap!(_t, "PREPROCESS |   gamma 0.3          {:>6} ms");
+/// Niveaux de gris pondérés par canal (canal rouge seul pour Elden Ring)
+pub fn extract_text_channel(
+    image: &DynamicImage,
+    profile: &ColorProfile,
+) -> ImageBuffer<Luma<u8>, Vec<u8>> {
+    let rgba = as_rgba(image);
+    let raw = rgba
+        .as_raw()
+        .chunks_exact(4)
+        .map(|p| profile.weighted_luma(p[0], p[1], p[2]))
+        .collect();
+    GrayImage::from_raw(image.width(), image.height(), raw).unwrap_or_default()
+}
 
-    let _t = Instant::now();
-    let contrast = increase_contrast(&gamma, 2.0);
-    lap!(_t, "PREPROCESS |   contrast x2.0     {:>6} ms");
-    let (w, h) = contrast.dimensions();
+fn resize_to_death_height(img: GrayImage) -> DynamicImage {
+    let (w, h) = img.dimensions();
+    let scale = DEATH_TARGET_HEIGHT as f32 / h.max(1) as f32;
+    DynamicImage::ImageLuma8(img).resize(
+        (w as f32 * scale) as u32,
+        DEATH_TARGET_HEIGHT,
+        FilterType::CatmullRom,
+    )
+}
 
-    const TARGET_HEIGHT: u32 = 120;
-    let scale = TARGET_HEIGHT as f32 / h as f32;
+#[instrument(level = "trace", target = "timing", skip_all)]
+pub fn preprocess_v1_fast(red: &ImageBuffer<Luma<u8>, Vec<u8>>) -> DynamicImage {
+    let brightened = {
+        let _span = trace_span!(target: "timing", "gamma_0_4").entered();
+        adjust_gamma(red, 0.4)
+    };
 
-    let _t = Instant::now();
-    let scaled = DynamicImage::ImageLuma8(contrast).resize(
-        (w as f32 * scale) as u32,
-        TARGET_HEIGHT,
-        image::imageops::FilterType::CatmullRom,
-    );
+    let _span = trace_span!(target: "timing", "resize").entered();
+    resize_to_death_height(brightened)
+}
 
-    lap!(_t, "PREPROCESS |   resize → 120px     {:>6} ms");
+#[instrument(level = "trace", target = "timing", skip_all)]
+pub fn preprocess_v2_fallback(red: &ImageBuffer<Luma<u8>, Vec<u8>>) -> DynamicImage {
+    // Gamma 0.3 puis contraste x2.0 en une seule passe
+    let contrast = {
+        let _span = trace_span!(target: "timing", "gamma_0_3_contrast_2").entered();
+        let mut contrast = red.clone();
+        let lut = compose_luts(&gamma_lut(0.3), &contrast_lut(2.0));
+        apply_lut_in_place(&mut contrast, &lut);
+        contrast
+    };
 
-    scaled
+    let _span = trace_span!(target: "timing", "resize").entered();
+    resize_to_death_height(contrast)
 }
 //////////////////////////////////////////////////////////////////
 /////////////////////PROCESSING BOSSES////////////////////////////
 //////////////////////////////////////////////////////////////////
 
-pub fn process_boss_gamma(dyn_image: &DynamicImage, gamma: f32) -> DynamicImage {
+/// Barre de boss en niveaux de gris agrandie : calculée une fois, partagée par toutes
+/// les variantes de prétraitement
+pub fn upscale_boss_crop(dyn_image: &DynamicImage) -> GrayImage {
     let gray = dyn_image.to_luma8();
-    let mut enhanced = gray.clone();
+    let (w, h) = gray.dimensions();
+    image::imageops::resize(&gray, w * BOSS_UPSCALE, h * BOSS_UPSCALE, BOSS_FILTER)
+}
 
-    for pixel in enhanced.pixels_mut() {
-        let val = pixel[0] as f32 / 255.0;
-        let corrected = (val.powf(gamma) * 255.0).clamp(0.0, 255.0);
-        pixel[0] = corrected as u8;
-    }
+/// Une variante de prétraitement à partir de la barre déjà agrandie
+pub fn boss_variant(upscaled: &GrayImage, lut: &Lut) -> DynamicImage {
+    let mut variant = upscaled.clone();
+    apply_lut_in_place(&mut variant, lut);
+    DynamicImage::ImageLuma8(variant)
+}
 
-    let (w, h) = enhanced.dimensions();
-    DynamicImage::ImageLuma8(enhanced).resize(w * 4, h * 4, image::imageops::FilterType::Lanczos3)
+pub fn process_boss_gamma(dyn_image: &DynamicImage, gamma: f32) -> DynamicImage {
+    let mut upscaled = upscale_boss_crop(dyn_image);
+    apply_lut_in_place(&mut upscaled, &gamma_lut(gamma));
+    DynamicImage::ImageLuma8(upscaled)
 }
 
-pub fn process_boss_gamma_contrast(
-    dyn_image: &DynamicImage,
-    gamma: f32,
-    contrast: f32,
-) -> DynamicImage {
-    let gray = dyn_image.to_luma8();
-    let mut enhanced = gray.clone();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luts_match_the_per_pixel_formulas() {
+        let gamma = gamma_lut(0.4);
+        let contrast = contrast_lut(2.0);
+        let gamma_contrast = gamma_contrast_lut(0.3, 1.5);
+        for value in 0..=255u8 {
+            let v = value as f32;
+            assert_eq!(gamma[value as usize], ((v / 255.0).powf(0.4) * 255.0) as u8);
+            assert_eq!(
+                contrast[value as usize],
+                ((v - 128.0) * 2.0 + 128.0).clamp(0.0, 255.0) as u8
+            );
+            let expected =
+                ((((v / 255.0).powf(0.3) - 0.5) * 1.5 + 0.5).clamp(0.0, 1.0) * 255.0) as u8;
+            assert_eq!(gamma_contrast[value as usize], expected);
+        }
+
+        // Deux passes successives == une passe avec les tables composées
+        let img = GrayImage::from_fn(16, 16, |x, y| Luma([(x * 16 + y) as u8]));
+        let mut two_passes = adjust_gamma(&img, 0.3);
+        apply_lut_in_place(&mut two_passes, &contrast_lut(2.0));
+        let mut one_pass = img.clone();
+        apply_lut_in_place(
+            &mut one_pass,
+            &compose_luts(&gamma_lut(0.3), &contrast_lut(2.0)),
+        );
+        assert_eq!(two_passes, one_pass);
+    }
+
+    #[test]
+    fn text_channel_reads_rgba_and_rgb_captures_alike() {
+        let profile = ColorProfile::default();
+        let rgba = RgbaImage::from_fn(8, 4, |x, y| {
+            image::Rgba([(x * 30) as u8, (y * 60) as u8, 10, 255])
+        });
+        let rgb = DynamicImage::ImageRgba8(rgba.clone()).to_rgb8();
 
-    for pixel in enhanced.pixels_mut() {
-        let val = pixel[0] as f32 / 255.0;
-        let gamma_corrected = val.powf(gamma);
-        let contrasted = ((gamma_corrected - 0.5) * contrast + 0.5).clamp(0.0, 1.0);
-        pixel[0] = (contrasted * 255.0) as u8;
+        let from_rgba = extract_text_channel(&DynamicImage::ImageRgba8(rgba), &profile);
+        let from_rgb = extract_text_channel(&DynamicImage::ImageRgb8(rgb), &profile);
+        assert_eq!(from_rgba, from_rgb);
+        assert_eq!(from_rgba.dimensions(), (8, 4));
     }
 
-    let (w, h) = enhanced.dimensions();
-    DynamicImage::ImageLuma8(enhanced).resize(w * 4, h * 4, image::imageops::FilterType::Lanczos3)
+    #[test]
+    fn glyph_shape_needs_a_tall_centred_stroke_pattern() {
+        let profile = ColorProfile::sekiro();
+        let red = image::Rgba([200, 20, 20, 255]);
+        let dark = image::Rgba([15, 10, 10, 255]);
+        let zone = |is_red: &dyn Fn(u32, u32) -> bool| {
+            DynamicImage::ImageRgba8(RgbaImage::from_fn(160, 120, |x, y| {
+                if is_red(x, y) { red } else { dark }
+            }))
+        };
+
+        // Traits d'un idéogramme : deux barres horizontales, deux verticales, un point isolé
+        let glyph = zone(&|x, y| {
+            let in_box = (45..115).contains(&x) && (20..100).contains(&y);
+            in_box
+                && ((20..28).contains(&y)
+                    || (60..66).contains(&y)
+                    || (50..58).contains(&x)
+                    || (95..103).contains(&x))
+                || (x == 5 && y == 5)
+        });
+        assert!(has_glyph_shape(&glyph, &profile));
+
+        // Écran teinté de rouge, barre de vie rouge, glyphe collé au bord
+        assert!(!has_glyph_shape(&zone(&|_, _| true), &profile));
+        assert!(!has_glyph_shape(
+            &zone(&|_, y| (100..108).contains(&y)),
+            &profile
+        ));
+        assert!(!has_glyph_shape(
+            &zone(&|x, y| x < 30 && (20..100).contains(&y) && x % 8 < 3),
+            &profile
+        ));
+    }
 }
@@ -0,0 +1,214 @@
+// src/utils/logging.rs
+//
+// Journalisation `tracing` : console, fichier journal quotidien dans le répertoire de
+// données (quelques jours gardés) et tampon mémoire affiché par la page des journaux.
+// Le niveau se change à chaud depuis les paramètres, sans redémarrer l'application.
+// Les étapes de l'OCR sont des spans (cible `timing`, niveau TRACE) : leur durée est
+// écrite à leur fermeture.
+
+use crate::structs::settings::log_level::LogLevel;
+use crate::structs::storage::Storage;
+use std::collections::VecDeque;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{Registry, reload};
+
+const LOG_FILE_PREFIX: &str = "deathcompteur";
+/// Fichiers journaux gardés (un par jour)
+const LOG_FILES_KEPT: usize = 7;
+/// Lignes gardées en mémoire pour la page des journaux
+const BUFFER_LINES: usize = 500;
+
+/// Cible des spans de chronométrage de l'OCR
+const TIMING_TARGET: &str = "timing";
+/// Niveau maximal des événements des dépendances (iced, wgpu, winit, `log`…)
+const DEPENDENCIES_LEVEL: LevelFilter = LevelFilter::WARN;
+
+static FILTER_HANDLE: OnceLock<reload::Handle<Targets, Registry>> = OnceLock::new();
+static RECENT: LogBuffer = LogBuffer::new(BUFFER_LINES);
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Dernières lignes du journal, les plus anciennes d'abord
+struct LogBuffer {
+    lines: Mutex<VecDeque<String>>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    const fn new(capacity: usize) -> Self {
+        Self {
+            lines: Mutex::new(VecDeque::new()),
+            capacity,
+        }
+    }
+
+    fn push(&self, text: &str) {
+        let Ok(mut lines) = self.lines.lock() else {
+            return;
+        };
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            if lines.len() == self.capacity {
+                lines.pop_front();
+            }
+            lines.push_back(line.to_string());
+        }
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.lines
+            .lock()
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Écrivain `tracing` vers le tampon mémoire
+struct BufferWriter;
+
+impl io::Write for BufferWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        RECENT.push(&String::from_utf8_lossy(buf));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for BufferWriter {
+    type Writer = BufferWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        BufferWriter
+    }
+}
+
+/// Filtre du journal : le niveau choisi ne s'applique qu'à l'application et à ses
+/// durées d'étapes, les dépendances restent limitées aux avertissements
+fn targets(level: LogLevel) -> Targets {
+    let level = LevelFilter::from(level);
+    Targets::new()
+        .with_default(DEPENDENCIES_LEVEL.min(level))
+        .with_target(env!("CARGO_CRATE_NAME"), level)
+        .with_target(TIMING_TARGET, level)
+}
+
+/// Dossier des fichiers journaux (créé si besoin)
+pub fn log_dir() -> Result<PathBuf, String> {
+    let dir = Storage::get_data_dir()?.join("logs");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Installer la journalisation ; le garde doit vivre jusqu'à la fin du programme
+/// pour que le fichier journal soit entièrement écrit
+pub fn init(level: LogLevel) -> Option<WorkerGuard> {
+    let (filter, handle) = reload::Layer::new(targets(level));
+
+    // Sans répertoire de données : console et tampon mémoire seulement
+    let file = log_dir().and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix("log")
+            .max_log_files(LOG_FILES_KEPT)
+            .build(dir)
+            .map_err(|e| e.to_string())
+    });
+    let (file_layer, guard) = match file {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_span_events(FmtSpan::CLOSE)
+                .with_writer(writer);
+            (Some(layer), Some(guard))
+        }
+        Err(e) => {
+            eprintln!("⚠️ Fichier journal indisponible : {}", e);
+            (None, None)
+        }
+    };
+
+    let installed = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_span_events(FmtSpan::CLOSE))
+        .with(file_layer)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_target(false)
+                .with_span_events(FmtSpan::CLOSE)
+                .with_writer(BufferWriter),
+        )
+        .try_init();
+    if installed.is_ok() {
+        let _ = FILTER_HANDLE.set(handle);
+    }
+    guard
+}
+
+/// Changer le niveau du journal à chaud
+pub fn set_level(level: LogLevel) {
+    if let Some(handle) = FILTER_HANDLE.get()
+        && let Err(e) = handle.reload(targets(level))
+    {
+        tracing::warn!("Changement du niveau de journal impossible : {}", e);
+    }
+}
+
+/// Dernières lignes du journal pour la page des journaux
+pub fn recent_lines() -> Vec<String> {
+    RECENT.snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_keeps_only_the_latest_lines() {
+        let buffer = LogBuffer::new(3);
+        buffer.push("une\ndeux\n");
+        buffer.push("\n");
+        buffer.push("trois\nquatre\n");
+        assert_eq!(buffer.snapshot(), vec!["deux", "trois", "quatre"]);
+    }
+
+    #[test]
+    fn level_applies_to_the_app_only() {
+        use tracing::Level;
+
+        let filter = targets(LogLevel::Trace);
+        let app = concat!(env!("CARGO_CRATE_NAME"), "::utils::app_worker");
+        assert!(filter.would_enable(app, &Level::TRACE));
+        assert!(filter.would_enable(TIMING_TARGET, &Level::TRACE));
+        assert!(!filter.would_enable("wgpu_core::device", &Level::INFO));
+        assert!(filter.would_enable("wgpu_core::device", &Level::WARN));
+
+        // Niveau bas : les dépendances ne passent pas au-dessus
+        let filter = targets(LogLevel::Error);
+        assert!(!filter.would_enable(app, &Level::WARN));
+        assert!(!filter.would_enable("winit", &Level::WARN));
+    }
+}
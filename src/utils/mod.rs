@@ -1,3 +1,12 @@
 pub mod app_worker;
+pub mod boss_cache;
+pub mod export;
+pub mod frame_diff;
+pub mod frame_source;
+pub mod game_detection;
+pub mod headless;
 pub mod image_processing;
+pub mod logging;
+pub mod ocr_health;
+pub mod scan_budget;
 pub mod screen_capture;
@@ -0,0 +1,131 @@
+// src/utils/ocr_health.rs
+//
+// Santé du worker OCR : échecs consécutifs, attente progressive après une erreur
+// et fréquence de scan, remontées périodiquement à l'interface.
+
+use crate::utils::scan_budget::BudgetReport;
+use std::time::{Duration, Instant};
+
+/// Attente après le premier échec, doublée à chaque échec suivant
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// Intervalle entre deux rapports envoyés à l'interface
+const REPORT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Attente avant de réessayer après `failures` échecs consécutifs
+pub fn backoff_delay(failures: u32) -> Duration {
+    if failures == 0 {
+        return Duration::ZERO;
+    }
+    let factor = 1u32 << (failures - 1).min(16);
+    BACKOFF_BASE.saturating_mul(factor).min(BACKOFF_MAX)
+}
+
+#[derive(Debug, Clone)]
+pub struct OcrHealth {
+    /// Dernière image capturée et analysée sans erreur
+    pub last_success: Option<Instant>,
+    /// Scans par seconde sur le dernier intervalle de rapport
+    pub scan_rate: f32,
+    /// Cadence visée et coût des étapes selon le budget CPU
+    pub budget: Option<BudgetReport>,
+    pub last_error: Option<String>,
+    pub capture_failures: u32,
+    pub ocr_failures: u32,
+    window_start: Instant,
+    window_scans: u32,
+}
+
+impl Default for OcrHealth {
+    fn default() -> Self {
+        Self {
+            last_success: None,
+            scan_rate: 0.0,
+            budget: None,
+            last_error: None,
+            capture_failures: 0,
+            ocr_failures: 0,
+            window_start: Instant::now(),
+            window_scans: 0,
+        }
+    }
+}
+
+impl OcrHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scan complet réussi (capture + OCR)
+    pub fn record_success(&mut self) {
+        self.last_success = Some(Instant::now());
+        self.capture_failures = 0;
+        self.ocr_failures = 0;
+        self.window_scans += 1;
+    }
+
+    /// Échec de capture, renvoie l'attente avant la prochaine tentative
+    pub fn record_capture_error(&mut self, error: String) -> Duration {
+        self.capture_failures += 1;
+        self.last_error = Some(error);
+        backoff_delay(self.capture_failures)
+    }
+
+    /// Échec du moteur OCR, renvoie l'attente avant la prochaine tentative
+    pub fn record_ocr_error(&mut self, error: String) -> Duration {
+        self.ocr_failures += 1;
+        self.last_error = Some(error);
+        backoff_delay(self.ocr_failures)
+    }
+
+    /// Dernier état du budget CPU, envoyé avec le prochain rapport
+    pub fn record_budget(&mut self, report: BudgetReport) {
+        self.budget = Some(report);
+    }
+
+    pub fn is_failing(&self) -> bool {
+        self.capture_failures > 0 || self.ocr_failures > 0
+    }
+
+    /// Rapport à envoyer à l'interface, au plus une fois par `REPORT_INTERVAL`
+    pub fn report_due(&mut self) -> Option<OcrHealth> {
+        let elapsed = self.window_start.elapsed();
+        if elapsed < REPORT_INTERVAL {
+            return None;
+        }
+        self.scan_rate = self.window_scans as f32 / elapsed.as_secs_f32();
+        self.window_start = Instant::now();
+        self.window_scans = 0;
+        Some(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_until_the_cap() {
+        assert_eq!(backoff_delay(0), Duration::ZERO);
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(2), Duration::from_secs(1));
+        assert_eq!(backoff_delay(4), Duration::from_secs(4));
+        assert_eq!(backoff_delay(10), BACKOFF_MAX);
+        assert_eq!(backoff_delay(u32::MAX), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn success_resets_consecutive_failures() {
+        let mut health = OcrHealth::new();
+        health.record_capture_error("écran introuvable".to_string());
+        let delay = health.record_capture_error("écran introuvable".to_string());
+        assert_eq!(delay, Duration::from_secs(1));
+        assert!(health.is_failing());
+
+        health.record_success();
+        assert!(!health.is_failing());
+        assert!(health.last_success.is_some());
+        // La dernière erreur reste affichée
+        assert_eq!(health.last_error.as_deref(), Some("écran introuvable"));
+    }
+}
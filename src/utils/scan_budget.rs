@@ -0,0 +1,228 @@
+// src/utils/scan_budget.rs
+//
+// Budget CPU du worker OCR : le temps passé à capturer et analyser ne doit pas dépasser
+// une fraction du temps réel. Chaque étape du scan est chronométrée (moyenne glissante),
+// l'intervalle entre deux scans s'allonge quand le coût dépasse le budget, et si ça ne
+// suffit pas les variantes de prétraitement les plus coûteuses sont abandonnées.
+
+use std::time::Duration;
+use tracing::info;
+
+/// Poids de la dernière mesure dans la moyenne glissante
+const SMOOTHING: f32 = 0.2;
+/// Intervalle maximal : au-delà, un écran de mort pourrait passer inaperçu
+const MAX_INTERVAL: Duration = Duration::from_secs(3);
+/// Mode économe quand l'intervalle nécessaire dépasse la cadence cible de ce facteur…
+const REDUCE_ABOVE: f32 = 1.5;
+/// … et retour au mode complet sous ce facteur (hystérésis)
+const RESTORE_BELOW: f32 = 0.75;
+/// Budget CPU accepté (en % d'un cœur)
+pub const CPU_BUDGET_RANGE: std::ops::RangeInclusive<u8> = 5..=100;
+
+/// Variantes de prétraitement utilisées par l'OCR
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preprocessing {
+    /// Deuxième passe (gamma + contraste) si la première lecture de mort échoue
+    pub death_fallback: bool,
+    /// Nombre maximal de variantes lues par barre de boss
+    pub boss_variants: usize,
+}
+
+impl Preprocessing {
+    pub const FULL: Preprocessing = Preprocessing {
+        death_fallback: true,
+        boss_variants: usize::MAX,
+    };
+    pub const REDUCED: Preprocessing = Preprocessing {
+        death_fallback: false,
+        boss_variants: 2,
+    };
+}
+
+/// Étapes chronométrées d'un scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanStage {
+    Capture,
+    Death,
+    Bosses,
+}
+
+/// Durée moyenne de chaque étape d'un scan
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StageTimings {
+    pub capture: Duration,
+    pub death: Duration,
+    pub bosses: Duration,
+}
+
+impl StageTimings {
+    pub fn total(&self) -> Duration {
+        self.capture + self.death + self.bosses
+    }
+}
+
+/// État du budget remonté à l'interface
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BudgetReport {
+    /// Cadence visée (scans/s)
+    pub target_rate: f32,
+    /// Part d'un cœur utilisée à cette cadence (0.0 – 1.0)
+    pub cpu_usage: f32,
+    pub cpu_budget: f32,
+    pub reduced: bool,
+    pub timings: StageTimings,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScanBudget {
+    /// Cadence normale, jamais dépassée
+    base: Duration,
+    /// Fraction d'un cœur accordée au scan
+    budget: f32,
+    timings: StageTimings,
+    interval: Duration,
+    reduced: bool,
+}
+
+impl ScanBudget {
+    pub fn new(base: Duration, cpu_budget: u8) -> Self {
+        let percent = cpu_budget.clamp(*CPU_BUDGET_RANGE.start(), *CPU_BUDGET_RANGE.end());
+        Self {
+            base,
+            budget: percent as f32 / 100.0,
+            timings: StageTimings::default(),
+            interval: base,
+            reduced: false,
+        }
+    }
+
+    /// Durée d'une étape du scan en cours
+    pub fn record(&mut self, stage: ScanStage, elapsed: Duration) {
+        let average = match stage {
+            ScanStage::Capture => &mut self.timings.capture,
+            ScanStage::Death => &mut self.timings.death,
+            ScanStage::Bosses => &mut self.timings.bosses,
+        };
+        *average = if average.is_zero() {
+            elapsed
+        } else {
+            average.mul_f32(1.0 - SMOOTHING) + elapsed.mul_f32(SMOOTHING)
+        };
+    }
+
+    /// Étape non exécutée sur ce scan : compte pour zéro, son coût est ainsi réparti
+    /// sur tous les scans au lieu d'être ajouté à chacun
+    pub fn skip(&mut self, stage: ScanStage) {
+        self.record(stage, Duration::ZERO);
+    }
+
+    /// Fin d'un scan : recalcule l'intervalle et le mode de prétraitement
+    pub fn adjust(&mut self) -> Duration {
+        let needed = self.timings.total().div_f32(self.budget);
+        let ratio = needed.as_secs_f32() / self.base.as_secs_f32();
+        if !self.reduced && ratio > REDUCE_ABOVE {
+            info!("🐢 Budget CPU dépassé : prétraitement réduit");
+            self.reduced = true;
+        } else if self.reduced && ratio < RESTORE_BELOW {
+            info!("🐇 Budget CPU retrouvé : prétraitement complet");
+            self.reduced = false;
+        }
+        self.interval = needed.clamp(self.base, MAX_INTERVAL);
+        self.interval
+    }
+
+    pub fn preprocessing(&self) -> Preprocessing {
+        if self.reduced {
+            Preprocessing::REDUCED
+        } else {
+            Preprocessing::FULL
+        }
+    }
+
+    pub fn report(&self) -> BudgetReport {
+        BudgetReport {
+            target_rate: 1.0 / self.interval.as_secs_f32(),
+            cpu_usage: self.timings.total().as_secs_f32() / self.interval.as_secs_f32(),
+            cpu_budget: self.budget,
+            reduced: self.reduced,
+            timings: self.timings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(budget: &mut ScanBudget, ms: u64) -> Duration {
+        budget.record(ScanStage::Capture, Duration::from_millis(ms / 4));
+        budget.record(ScanStage::Death, Duration::from_millis(ms - ms / 4));
+        budget.adjust()
+    }
+
+    #[test]
+    fn interval_stretches_to_stay_within_budget() {
+        let base = Duration::from_millis(500);
+        let mut budget = ScanBudget::new(base, 20);
+
+        // 40 ms par scan : 8 % d'un cœur à 2 scans/s, dans le budget
+        assert_eq!(scan(&mut budget, 40), base);
+        assert_eq!(budget.preprocessing(), Preprocessing::FULL);
+
+        // 200 ms par scan : il faut 1 s entre deux scans pour rester à 20 %
+        let mut slow = ScanBudget::new(base, 20);
+        let interval = scan(&mut slow, 200);
+        assert!((interval.as_secs_f32() - 1.0).abs() < 0.01);
+        let report = slow.report();
+        assert!((report.target_rate - 1.0).abs() < 0.01);
+        assert!((report.cpu_usage - 0.2).abs() < 0.01);
+
+        // Coût énorme : intervalle plafonné
+        let mut stalled = ScanBudget::new(base, 20);
+        assert_eq!(scan(&mut stalled, 5_000), MAX_INTERVAL);
+    }
+
+    #[test]
+    fn preprocessing_is_reduced_then_restored_with_hysteresis() {
+        let base = Duration::from_millis(500);
+        let mut budget = ScanBudget::new(base, 10);
+
+        scan(&mut budget, 200);
+        assert_eq!(budget.preprocessing(), Preprocessing::REDUCED);
+
+        // Juste sous la cadence cible : on reste économe
+        for _ in 0..30 {
+            scan(&mut budget, 45);
+        }
+        assert_eq!(budget.preprocessing(), Preprocessing::REDUCED);
+
+        for _ in 0..30 {
+            scan(&mut budget, 20);
+        }
+        assert_eq!(budget.preprocessing(), Preprocessing::FULL);
+    }
+
+    #[test]
+    fn occasional_stage_is_spread_over_all_scans() {
+        let base = Duration::from_millis(500);
+        let mut budget = ScanBudget::new(base, 20);
+
+        // Barres de boss (100 ms) vérifiées un scan sur quatre
+        let mut interval = base;
+        for i in 0..40 {
+            budget.record(ScanStage::Capture, Duration::from_millis(10));
+            budget.record(ScanStage::Death, Duration::from_millis(30));
+            if i % 4 == 0 {
+                budget.record(ScanStage::Bosses, Duration::from_millis(100));
+            } else {
+                budget.skip(ScanStage::Bosses);
+            }
+            interval = budget.adjust();
+        }
+        let bosses = budget.report().timings.bosses;
+        assert!(bosses < Duration::from_millis(40), "{bosses:?}");
+        // ~65 ms par scan en moyenne : 13 % d'un cœur, la cadence normale tient
+        assert_eq!(interval, base);
+        assert_eq!(budget.preprocessing(), Preprocessing::FULL);
+    }
+}
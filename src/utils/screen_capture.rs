@@ -1,52 +1,268 @@
 // src/utils/screen_capture.rs
 use image::DynamicImage;
 use image::RgbaImage;
-use xcap::Monitor;
-
-use crate::structs::settings::crop_position::CropPosition;
-
-/// Capture l'écran complet (sans crop)
-pub async fn capture_full_screen(screen: i8) -> Result<DynamicImage, String> {
-    let (dyn_image, _w, _h) = tokio::task::spawn_blocking(move || capture_screen(screen))
-        .await
-        .map_err(|e| format!("Erreur join: {}", e))?
-        .map_err(|e| format!("Erreur capture: {}", e))?;
-
-    #[cfg(feature = "debug")]
-    {
-        let _t = std::time::Instant::now();
-        dyn_image.save("full_screen.png").ok();
-        lap!(_t, "Save crop (disk)");
-    }
+use xcap::{Monitor, Window};
 
-    Ok(dyn_image)
-}
+use crate::structs::settings::crop_position::{ContentArea, CropPosition};
 
-/// Capture l'écran spécifié (fonction de base)
-fn capture_screen(monitor_index: i8) -> Result<(DynamicImage, u32, u32), String> {
+fn find_monitor(monitor_index: i8) -> Result<Monitor, String> {
     let monitors = Monitor::all().map_err(|e| format!("Erreur Monitor::all: {}", e))?;
 
-    let monitor = monitors
+    monitors
         .into_iter()
         .nth(monitor_index as usize)
-        .ok_or(format!("Écran {} non trouvé", monitor_index))?;
+        .ok_or(format!("Écran {} non trouvé", monitor_index))
+}
 
-    let image = monitor
+/// Capture l'écran spécifié (fonction de base)
+pub fn capture_screen(monitor_index: i8) -> Result<(DynamicImage, u32, u32), String> {
+    let rgba = capture_screen_rgba(monitor_index)?;
+    let (width, height) = rgba.dimensions();
+
+    Ok((DynamicImage::ImageRgba8(rgba), width, height))
+}
+
+/// Capture brute de l'écran, à cropper avant toute conversion
+pub fn capture_screen_rgba(monitor_index: i8) -> Result<RgbaImage, String> {
+    let image = find_monitor(monitor_index)?
         .capture_image()
         .map_err(|e| format!("Erreur capture écran: {}", e))?;
 
     let width = image.width();
     let height = image.height();
 
-    let rgba =
-        RgbaImage::from_raw(width, height, image.into_raw()).ok_or("Buffer RGBA invalide")?;
+    RgbaImage::from_raw(width, height, image.into_raw()).ok_or("Buffer RGBA invalide".to_string())
+}
 
-    Ok((DynamicImage::ImageRgba8(rgba), width, height))
+/// Capture uniquement un rectangle de l'écran
+///
+/// `region` est en pixels d'une capture complète de taille `frame_size` ; le backend
+/// peut travailler dans une autre échelle (coordonnées logiques), on convertit.
+pub fn capture_screen_region(
+    monitor_index: i8,
+    (x, y, width, height): (u32, u32, u32, u32),
+    frame_size: (u32, u32),
+) -> Result<DynamicImage, String> {
+    let monitor = find_monitor(monitor_index)?;
+    let monitor_width = monitor
+        .width()
+        .map_err(|e| format!("Erreur monitor.width(): {}", e))?;
+    let scale = monitor_width as f32 / frame_size.0.max(1) as f32;
+    let scaled = |value: u32| (value as f32 * scale).round() as u32;
+
+    let image = monitor
+        .capture_region(
+            scaled(x),
+            scaled(y),
+            scaled(width).max(1),
+            scaled(height).max(1),
+        )
+        .map_err(|e| format!("Erreur capture zone: {}", e))?;
+
+    let rgba = RgbaImage::from_raw(image.width(), image.height(), image.into_raw())
+        .ok_or("Buffer RGBA invalide")?;
+    Ok(DynamicImage::ImageRgba8(rgba))
+}
+
+/// Capture la zone client de la fenêtre du jeu, même si elle est recouverte
+pub fn capture_window(window_name: &str) -> Result<(DynamicImage, u32, u32), String> {
+    let image = capture_window_rgba(window_name)?;
+    let (width, height) = image.dimensions();
+
+    Ok((DynamicImage::ImageRgba8(image), width, height))
+}
+
+/// Capture brute de la fenêtre réduite à sa zone client, à cropper avant toute conversion :
+/// les pourcentages des zones ne doivent pas être décalés par la barre de titre et les bordures
+pub fn capture_window_rgba(window_name: &str) -> Result<RgbaImage, String> {
+    let frame = capture_window_frame(&find_window(window_name)?)?;
+    let (x, y, width, height) = frame.client;
+    if (width, height) == frame.image.dimensions() {
+        return Ok(frame.image);
+    }
+    Ok(image::imageops::crop_imm(&frame.image, x, y, width, height).to_image())
+}
+
+/// Capture brute d'une fenêtre et rectangle (x, y, largeur, hauteur) de sa zone client
+/// dans cette capture : les zones y sont cropées directement, sans copie de la zone client
+pub struct WindowFrame {
+    pub image: RgbaImage,
+    pub client: (u32, u32, u32, u32),
+}
+
+pub fn capture_window_frame(window: &Window) -> Result<WindowFrame, String> {
+    let image = window
+        .capture_image()
+        .map_err(|e| format!("Erreur capture fenêtre: {}", e))?;
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return Err("Fenêtre vide".to_string());
+    }
+
+    let client = client_rect(window, (width, height)).unwrap_or((0, 0, width, height));
+    Ok(WindowFrame { image, client })
+}
+
+/// Géométrie d'une fenêtre en coordonnées écran : cadre complet, origine et taille
+/// de la zone client
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct WindowGeometry {
+    frame: (i32, i32, i32, i32),
+    client_origin: (i32, i32),
+    client_size: (i32, i32),
 }
 
-pub fn crop_image_crop_position(image: DynamicImage, config: CropPosition) -> DynamicImage {
-    let w = image.width();
-    let h = image.height();
-    let pixel = config.to_pixels(w, h);
-    image.crop_imm(pixel.0, pixel.1, pixel.2, pixel.3)
+/// Rectangle de la zone client dans une capture de la fenêtre de taille `captured`
+/// (la capture peut être à une autre échelle que les coordonnées écran)
+fn client_rect_in_capture(
+    geometry: WindowGeometry,
+    captured: (u32, u32),
+) -> Option<(u32, u32, u32, u32)> {
+    let (left, top, right, bottom) = geometry.frame;
+    let frame_width = right - left;
+    if frame_width <= 0 || bottom <= top {
+        return None;
+    }
+    let scale = captured.0 as f32 / frame_width as f32;
+    let scaled = |value: i32| (value.max(0) as f32 * scale).round() as u32;
+
+    let x = scaled(geometry.client_origin.0 - left).min(captured.0.saturating_sub(1));
+    let y = scaled(geometry.client_origin.1 - top).min(captured.1.saturating_sub(1));
+    let width = scaled(geometry.client_size.0).min(captured.0 - x);
+    let height = scaled(geometry.client_size.1).min(captured.1 - y);
+    (width > 0 && height > 0).then_some((x, y, width, height))
+}
+
+#[cfg(target_os = "windows")]
+fn window_geometry(window: &Window) -> Option<WindowGeometry> {
+    use windows::Win32::Foundation::{HWND, POINT, RECT};
+    use windows::Win32::Graphics::Gdi::ClientToScreen;
+    use windows::Win32::UI::WindowsAndMessaging::{GetClientRect, GetWindowRect};
+
+    let hwnd = HWND(window.id().ok()? as usize as *mut std::ffi::c_void);
+    let mut frame = RECT::default();
+    let mut client = RECT::default();
+    let mut origin = POINT::default();
+    unsafe {
+        GetWindowRect(hwnd, &mut frame).ok()?;
+        GetClientRect(hwnd, &mut client).ok()?;
+        if !ClientToScreen(hwnd, &mut origin).as_bool() {
+            return None;
+        }
+    }
+
+    Some(WindowGeometry {
+        frame: (frame.left, frame.top, frame.right, frame.bottom),
+        client_origin: (origin.x, origin.y),
+        client_size: (client.right - client.left, client.bottom - client.top),
+    })
+}
+
+/// Hors Windows, la capture xcap ne contient que le contenu de la fenêtre
+#[cfg(not(target_os = "windows"))]
+fn window_geometry(_window: &Window) -> Option<WindowGeometry> {
+    None
+}
+
+fn client_rect(window: &Window, captured: (u32, u32)) -> Option<(u32, u32, u32, u32)> {
+    client_rect_in_capture(window_geometry(window)?, captured)
+}
+
+/// Cherche une fenêtre non minimisée par son titre ou le nom de son processus :
+/// correspondance exacte d'abord, sous-chaîne seulement à défaut
+pub fn find_window(window_name: &str) -> Result<Window, String> {
+    let needle = window_name.trim().to_lowercase();
+    if needle.is_empty() {
+        return Err("Aucune fenêtre configurée".to_string());
+    }
+
+    let windows: Vec<Window> = Window::all()
+        .map_err(|e| format!("Erreur Window::all: {}", e))?
+        .into_iter()
+        .filter(|w| !w.is_minimized().unwrap_or(false))
+        .collect();
+    let names: Vec<(String, String)> = windows
+        .iter()
+        .map(|w| {
+            (
+                w.title().unwrap_or_default(),
+                w.app_name().unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    let index = best_window_match(&names, &needle)
+        .ok_or(format!("Fenêtre '{}' non trouvée", window_name))?;
+    Ok(windows
+        .into_iter()
+        .nth(index)
+        .expect("index de fenêtre valide"))
+}
+
+/// Index de la fenêtre (titre, processus) correspondant le mieux à `needle` (en minuscules)
+fn best_window_match(names: &[(String, String)], needle: &str) -> Option<usize> {
+    let normalized = |name: &str| {
+        let lower = name.trim().to_lowercase();
+        lower
+            .strip_suffix(".exe")
+            .map(str::to_string)
+            .unwrap_or(lower)
+    };
+    let needle = normalized(needle);
+
+    names
+        .iter()
+        .position(|(title, app)| normalized(title) == needle || normalized(app) == needle)
+        .or_else(|| {
+            names.iter().position(|(title, app)| {
+                normalized(title).contains(&needle) || normalized(app).contains(&needle)
+            })
+        })
+}
+
+pub fn crop_image_crop_position(
+    image: DynamicImage,
+    config: CropPosition,
+    content: ContentArea,
+) -> DynamicImage {
+    config.crop_image(&image, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_area_excludes_title_bar_and_borders() {
+        // Fenêtre 1296x759 : bordures de 8 px, barre de titre de 31 px, client 1280x720
+        let geometry = WindowGeometry {
+            frame: (100, 50, 1396, 809),
+            client_origin: (108, 81),
+            client_size: (1280, 720),
+        };
+        assert_eq!(
+            client_rect_in_capture(geometry, (1296, 759)),
+            Some((8, 31, 1280, 720))
+        );
+        // Capture en pixels physiques (échelle 150 %)
+        assert_eq!(
+            client_rect_in_capture(geometry, (1944, 1138)),
+            Some((12, 47, 1920, 1080))
+        );
+    }
+
+    #[test]
+    fn exact_window_match_wins_over_substring() {
+        let names = vec![
+            ("DARK SOULS III".to_string(), "DarkSoulsIII.exe".to_string()),
+            (
+                "Dark Souls".to_string(),
+                "DarkSoulsRemastered.exe".to_string(),
+            ),
+        ];
+        assert_eq!(best_window_match(&names, "dark souls"), Some(1));
+        assert_eq!(best_window_match(&names, "darksoulsiii"), Some(0));
+        assert_eq!(best_window_match(&names, "souls iii"), Some(0));
+        assert_eq!(best_window_match(&names, "sekiro"), None);
+    }
 }
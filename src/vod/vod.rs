@@ -0,0 +1,252 @@
+// vod.rs - Analyse d'une vidéo enregistrée (VOD) : timeline des morts
+
+use crate::ocr::ocr::{detect_death, get_boss_names};
+use crate::structs::settings::crop_position::ContentArea;
+use crate::structs::settings::game::GameConfig;
+use crate::structs::storage::Storage;
+use crate::utils::export::DeathMarker;
+use crate::utils::frame_source::{FrameSource, VideoFileSource};
+use image::DynamicImage;
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+/// Deux images positives plus proches que ça appartiennent à la même mort
+const DEATH_MERGE_WINDOW: Duration = Duration::from_secs(5);
+/// Largeur des miniatures enregistrées pour chaque mort
+const THUMBNAIL_WIDTH: u32 = 320;
+
+/// Une mort trouvée dans la vidéo
+#[derive(Debug, Clone)]
+pub struct VodDeath {
+    /// Position dans la vidéo
+    pub timestamp: Duration,
+    /// Noms des boss lus au moment de la mort (vide hors combat de boss)
+    pub bosses: Vec<String>,
+    /// Miniature de l'image au moment de la mort
+    pub thumbnail: Option<PathBuf>,
+}
+
+impl VodDeath {
+    pub fn to_marker(&self) -> DeathMarker {
+        DeathMarker::new(self.timestamp, self.bosses.clone())
+    }
+}
+
+/// Événements envoyés pendant l'analyse
+#[derive(Debug, Clone)]
+pub enum VodEvent {
+    /// Position atteinte dans la vidéo
+    Progress(Duration),
+    DeathFound(VodDeath),
+}
+
+/// Analyser une vidéo complète (bloquant, à lancer dans `spawn_blocking`)
+///
+/// Les images sont lues par lots, `detect_death` tourne en parallèle sur le lot
+/// puis `get_boss_names` en parallèle sur les nouvelles morts du lot.
+/// L'analyse s'arrête dès que `on_event` renvoie `false` (analyse annulée).
+pub fn analyze_vod(
+    path: &Path,
+    game_config: &GameConfig,
+    death_texts: &[String],
+    handle: &Handle,
+    mut on_event: impl FnMut(VodEvent) -> bool,
+) -> Result<Vec<VodDeath>, String> {
+    let mut source = VideoFileSource::new(path)?;
+    let thumbnail_dir = thumbnail_dir(path)?;
+
+    let death_zone = *game_config.get_death_zone();
+    let color_profile = *game_config.get_color_profile();
+    let boss_zones = game_config.get_boss_zones().clone();
+    let batch_size = rayon::current_num_threads().max(1) * 2;
+
+    let mut deaths: Vec<VodDeath> = Vec::new();
+    let mut last_positive: Option<Duration> = None;
+
+    loop {
+        // 1️⃣ Lire un lot d'images horodatées
+        // Zone utile validée sur les images précédentes : les écrans noirs ne la faussent pas
+        let mut batch: Vec<(Duration, DynamicImage, ContentArea)> = Vec::with_capacity(batch_size);
+        while batch.len() < batch_size {
+            match source.next_frame()? {
+                Some(frame) => {
+                    let content = source.content_area(&frame);
+                    batch.push((source.position().unwrap_or_default(), frame, content));
+                }
+                None => break,
+            }
+        }
+        if batch.is_empty() {
+            break;
+        }
+
+        // 2️⃣ Détection de mort en parallèle
+        let positives: Vec<bool> = batch
+            .par_iter()
+            .map(|(_, frame, content)| {
+                handle
+                    .block_on(detect_death(
+                        frame,
+                        *content,
+                        &death_zone,
+                        &color_profile,
+                        death_texts,
+                    ))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        // 3️⃣ Regrouper les images positives consécutives en une seule mort
+        let flags: Vec<(Duration, bool)> = batch
+            .iter()
+            .map(|(timestamp, _, _)| *timestamp)
+            .zip(positives)
+            .collect();
+        let starts = merge_positives(&flags, &mut last_positive);
+        let new_deaths: Vec<(Duration, DynamicImage, ContentArea)> = batch
+            .into_iter()
+            .filter(|(timestamp, _, _)| starts.contains(timestamp))
+            .collect();
+
+        // 4️⃣ Noms des boss + miniatures en parallèle
+        let first_index = deaths.len();
+        let found: Vec<VodDeath> = new_deaths
+            .into_par_iter()
+            .enumerate()
+            .map(|(i, (timestamp, frame, content))| {
+                let thumbnail = save_thumbnail(&frame, &thumbnail_dir, first_index + i + 1);
+                let bosses = handle
+                    .block_on(get_boss_names(frame, content, boss_zones.clone()))
+                    .unwrap_or_default();
+                VodDeath {
+                    timestamp,
+                    bosses,
+                    thumbnail,
+                }
+            })
+            .collect();
+
+        for death in found {
+            deaths.push(death.clone());
+            if !on_event(VodEvent::DeathFound(death)) {
+                return Ok(deaths);
+            }
+        }
+        if !on_event(VodEvent::Progress(source.position().unwrap_or_default())) {
+            return Ok(deaths);
+        }
+    }
+
+    Ok(deaths)
+}
+
+/// Début de chaque nouvelle mort parmi des images horodatées (positives ou non)
+///
+/// Une image positive à moins de `DEATH_MERGE_WINDOW` de la précédente appartient à la même mort.
+/// `last_positive` est conservé d'un lot à l'autre pour ne pas couper une mort entre deux lots.
+fn merge_positives(
+    frames: &[(Duration, bool)],
+    last_positive: &mut Option<Duration>,
+) -> Vec<Duration> {
+    let mut starts = Vec::new();
+    for &(timestamp, positive) in frames {
+        if !positive {
+            continue;
+        }
+        if last_positive.is_none_or(|t| timestamp.saturating_sub(t) > DEATH_MERGE_WINDOW) {
+            starts.push(timestamp);
+        }
+        *last_positive = Some(timestamp);
+    }
+    starts
+}
+
+/// Dossier des miniatures : `<data_dir>/vod/<nom de la vidéo>`
+fn thumbnail_dir(path: &Path) -> Result<PathBuf, String> {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "video".to_string());
+    let dir = Storage::get_data_dir()?.join("vod").join(stem);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn save_thumbnail(frame: &DynamicImage, dir: &Path, index: usize) -> Option<PathBuf> {
+    let height = frame.height() * THUMBNAIL_WIDTH / frame.width().max(1);
+    let path = dir.join(format!("death_{:03}.png", index));
+    frame
+        .thumbnail(THUMBNAIL_WIDTH, height.max(1))
+        .save(&path)
+        .ok()
+        .map(|_| path)
+}
+
+/// Formater une position vidéo en `HH:MM:SS`
+pub fn format_timestamp(timestamp: Duration) -> String {
+    let secs = timestamp.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_timestamp_pads_hours_minutes_seconds() {
+        assert_eq!(format_timestamp(Duration::from_secs(0)), "00:00:00");
+        assert_eq!(format_timestamp(Duration::from_millis(61_500)), "00:01:01");
+        assert_eq!(
+            format_timestamp(Duration::from_secs(3 * 3600 + 5)),
+            "03:00:05"
+        );
+    }
+
+    fn secs(s: f32) -> Duration {
+        Duration::from_secs_f32(s)
+    }
+
+    #[test]
+    fn merge_positives_groups_consecutive_frames_into_one_death() {
+        let mut last = None;
+        let frames = [
+            (secs(1.0), false),
+            (secs(1.5), true),
+            (secs(2.0), true),
+            (secs(2.5), false),
+            (secs(3.0), true),
+        ];
+
+        assert_eq!(merge_positives(&frames, &mut last), vec![secs(1.5)]);
+        assert_eq!(last, Some(secs(3.0)));
+    }
+
+    #[test]
+    fn merge_positives_keeps_a_death_across_batches() {
+        let mut last = None;
+        assert_eq!(
+            merge_positives(&[(secs(10.0), true), (secs(10.5), true)], &mut last),
+            vec![secs(10.0)]
+        );
+        // Le lot suivant continue la même mort
+        assert!(merge_positives(&[(secs(11.0), true), (secs(14.0), true)], &mut last).is_empty());
+    }
+
+    #[test]
+    fn merge_positives_splits_deaths_further_apart_than_the_window() {
+        let mut last = None;
+        let frames = [(secs(10.0), true), (secs(12.0), false), (secs(16.0), true)];
+
+        assert_eq!(
+            merge_positives(&frames, &mut last),
+            vec![secs(10.0), secs(16.0)]
+        );
+    }
+}